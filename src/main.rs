@@ -2,12 +2,14 @@
 
 use bevy::{
     dev_tools::{self},
-    input::common_conditions::*,
     prelude::*,
     winit::WinitSettings,
 };
+use settings::{Action, action_just_pressed};
 
+mod practice;
 mod prelude;
+mod settings;
 mod touhou;
 mod types;
 mod xcom;
@@ -24,6 +26,7 @@ fn main() {
     App::new()
         .insert_resource(WinitSettings::game())
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
+        .add_plugins((settings::settings_plugin, practice::practice_plugin))
         .add_plugins((xcom::xcom_plugin, touhou::touhou_plugin))
         .init_state::<GameState>()
         .add_systems(Startup, (global_setup, create_camera))
@@ -33,8 +36,8 @@ fn main() {
         .add_systems(
             Update,
             (
-                enter_xcom.run_if(input_just_pressed(KeyCode::KeyX)),
-                enter_touhou.run_if(input_just_pressed(KeyCode::KeyT)),
+                enter_xcom.run_if(action_just_pressed(Action::Fire)),
+                enter_touhou.run_if(action_just_pressed(Action::Debug)),
             )
                 .run_if(in_state(GameState::Menu)),
         )
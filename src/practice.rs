@@ -0,0 +1,63 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+const PRACTICE_PATH: &str = "practice.ron";
+
+pub fn practice_plugin(app: &mut App) {
+    app.insert_resource(PracticeStats::load());
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CardStats {
+    pub attempts: u32,
+    pub captures: u32,
+}
+
+/// How every spellcard has gone in practice, read from [`PRACTICE_PATH`] on
+/// startup and written back after each run.
+#[derive(Resource, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PracticeStats {
+    /// keyed by boss and the card's index in [`touhou::spellcards`]
+    cards: BTreeMap<(Enemies, usize), CardStats>,
+}
+
+impl PracticeStats {
+    pub fn load() -> Self {
+        let Ok(text) = std::fs::read_to_string(PRACTICE_PATH) else {
+            return default();
+        };
+
+        ron::from_str(&text).unwrap_or_else(|err| {
+            log::warn!("couldn't read {PRACTICE_PATH}, starting over: {err}");
+            default()
+        })
+    }
+
+    pub fn save(&self) {
+        let text = match ron::ser::to_string_pretty(self, default()) {
+            Ok(text) => text,
+            Err(err) => {
+                log::error!("couldn't serialize practice stats: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = std::fs::write(PRACTICE_PATH, text) {
+            log::error!("couldn't write {PRACTICE_PATH}: {err}");
+        }
+    }
+
+    pub fn card(&self, enemy: Enemies, card: usize) -> CardStats {
+        self.cards.get(&(enemy, card)).copied().unwrap_or_default()
+    }
+
+    pub fn record(&mut self, enemy: Enemies, card: usize, captured: bool) {
+        let stats = self.cards.entry((enemy, card)).or_default();
+        stats.attempts += 1;
+        stats.captures += captured as u32;
+    }
+}
@@ -1,2 +1,8 @@
-pub(crate) use crate::{touhou, types::*, GameState};
+pub(crate) use crate::{
+    GameState,
+    practice::PracticeStats,
+    settings::{Action, ActionInput, Settings, action_just_pressed},
+    touhou,
+    types::*,
+};
 pub(crate) use bevy::prelude::*;
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use bevy::ecs::system::SystemParam;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+const SETTINGS_PATH: &str = "settings.ron";
+
+// how far the stick has to be pushed before it counts as a direction
+const STICK_THRESHOLD: f32 = 0.5;
+// the range of fixed update rates that still give a usable timestep
+const MIN_FIXED_HZ: f64 = 16.0;
+const MAX_FIXED_HZ: f64 = 1000.0;
+
+pub fn settings_plugin(app: &mut App) {
+    let settings = Settings::load();
+
+    app.insert_resource(Time::<Fixed>::from_hz(settings.fixed_hz))
+        .insert_resource(settings);
+}
+
+/// User settings, read from [`SETTINGS_PATH`] on startup. Anything missing or
+/// unreadable falls back to the defaults.
+#[derive(Resource, Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub controls: Controls,
+    /// How many times a second the fixed update schedules run. Gameplay is in
+    /// units per second, so this only changes how finely it's simulated.
+    pub fixed_hz: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            controls: default(),
+            fixed_hz: 64.0,
+        }
+    }
+}
+
+impl Settings {
+    pub fn load() -> Self {
+        let Ok(text) = std::fs::read_to_string(SETTINGS_PATH) else {
+            return default();
+        };
+
+        match ron::from_str::<Settings>(&text) {
+            Ok(mut settings) => {
+                settings.controls.fill_defaults();
+
+                if !settings.fixed_hz.is_finite() {
+                    log::warn!("fixed_hz has to be a number, using the default");
+                    settings.fixed_hz = Settings::default().fixed_hz;
+                } else if !(MIN_FIXED_HZ..=MAX_FIXED_HZ).contains(&settings.fixed_hz) {
+                    log::warn!("fixed_hz has to be between {MIN_FIXED_HZ} and {MAX_FIXED_HZ}");
+                    settings.fixed_hz = settings.fixed_hz.clamp(MIN_FIXED_HZ, MAX_FIXED_HZ);
+                }
+
+                settings
+            }
+            Err(err) => {
+                log::warn!("couldn't read {SETTINGS_PATH}, using defaults: {err}");
+                default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let text = match ron::ser::to_string_pretty(self, default()) {
+            Ok(text) => text,
+            Err(err) => {
+                log::error!("couldn't serialize settings: {err}");
+                return;
+            }
+        };
+
+        if let Err(err) = std::fs::write(SETTINGS_PATH, text) {
+            log::error!("couldn't write {SETTINGS_PATH}: {err}");
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Fire,
+    Focus,
+    Bomb,
+    Pause,
+    Debug,
+}
+
+impl Action {
+    pub const ALL: [Action; 9] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Fire,
+        Action::Focus,
+        Action::Bomb,
+        Action::Pause,
+        Action::Debug,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Action::MoveUp => "Move up",
+            Action::MoveDown => "Move down",
+            Action::MoveLeft => "Move left",
+            Action::MoveRight => "Move right",
+            Action::Fire => "Fire",
+            Action::Focus => "Focus",
+            Action::Bomb => "Bomb",
+            Action::Pause => "Pause",
+            Action::Debug => "Debug",
+        }
+    }
+
+    fn default_binding(self) -> Binding {
+        let (keys, buttons) = match self {
+            Action::MoveUp => (
+                vec![KeyCode::KeyW, KeyCode::ArrowUp],
+                vec![GamepadButton::DPadUp],
+            ),
+            Action::MoveDown => (
+                vec![KeyCode::KeyS, KeyCode::ArrowDown],
+                vec![GamepadButton::DPadDown],
+            ),
+            Action::MoveLeft => (
+                vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+                vec![GamepadButton::DPadLeft],
+            ),
+            Action::MoveRight => (
+                vec![KeyCode::KeyD, KeyCode::ArrowRight],
+                vec![GamepadButton::DPadRight],
+            ),
+            Action::Fire => (vec![KeyCode::KeyZ], vec![GamepadButton::South]),
+            Action::Focus => (vec![KeyCode::ShiftLeft], vec![GamepadButton::RightTrigger]),
+            Action::Bomb => (vec![KeyCode::KeyX], vec![GamepadButton::East]),
+            Action::Pause => (vec![KeyCode::Escape], vec![GamepadButton::Start]),
+            Action::Debug => (vec![KeyCode::Space], vec![GamepadButton::Select]),
+        };
+
+        Binding { keys, buttons }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Binding {
+    pub keys: Vec<KeyCode>,
+    pub buttons: Vec<GamepadButton>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Controls {
+    bindings: BTreeMap<Action, Binding>,
+}
+
+impl Default for Controls {
+    fn default() -> Self {
+        let mut controls = Self {
+            bindings: BTreeMap::new(),
+        };
+        controls.fill_defaults();
+        controls
+    }
+}
+
+impl Controls {
+    /// Gives every action the settings file doesn't mention its default binding.
+    fn fill_defaults(&mut self) {
+        for action in Action::ALL {
+            self.bindings
+                .entry(action)
+                .or_insert_with(|| action.default_binding());
+        }
+    }
+
+    pub fn binding(&self, action: Action) -> &Binding {
+        &self.bindings[&action]
+    }
+
+    /// Makes `key` the only key for `action`, leaving its gamepad buttons alone.
+    pub fn rebind_key(&mut self, action: Action, key: KeyCode) {
+        self.bindings.entry(action).or_default().keys = vec![key];
+    }
+
+    /// Makes `button` the only gamepad button for `action`, leaving its keys alone.
+    pub fn rebind_button(&mut self, action: Action, button: GamepadButton) {
+        self.bindings.entry(action).or_default().buttons = vec![button];
+    }
+
+    pub fn describe(&self, action: Action) -> String {
+        let binding = self.binding(action);
+
+        let keys: Vec<_> = binding.keys.iter().map(|k| format!("{k:?}")).collect();
+        let buttons: Vec<_> = binding.buttons.iter().map(|b| format!("{b:?}")).collect();
+
+        format!("{} / {}", keys.join(", "), buttons.join(", "))
+    }
+}
+
+/// Reads [`Action`]s from the keyboard and every connected gamepad.
+#[derive(SystemParam)]
+pub struct ActionInput<'w, 's> {
+    settings: Res<'w, Settings>,
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ActionInput<'_, '_> {
+    pub fn pressed(&self, action: Action) -> bool {
+        let binding = self.settings.controls.binding(action);
+
+        self.keyboard.any_pressed(binding.keys.iter().copied())
+            || self.gamepads.iter().any(|pad| {
+                pad.any_pressed(binding.buttons.iter().copied()) || stick_pressed(pad, action)
+            })
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        let binding = self.settings.controls.binding(action);
+
+        self.keyboard.any_just_pressed(binding.keys.iter().copied())
+            || self
+                .gamepads
+                .iter()
+                .any(|pad| pad.any_just_pressed(binding.buttons.iter().copied()))
+    }
+}
+
+// the stick is read as eight-way, same as the keys, so a tick of input still
+// fits in a replay byte
+fn stick_pressed(pad: &Gamepad, action: Action) -> bool {
+    let stick = pad.left_stick();
+
+    match action {
+        Action::MoveUp => stick.y > STICK_THRESHOLD,
+        Action::MoveDown => stick.y < -STICK_THRESHOLD,
+        Action::MoveLeft => stick.x < -STICK_THRESHOLD,
+        Action::MoveRight => stick.x > STICK_THRESHOLD,
+        _ => false,
+    }
+}
+
+pub fn action_just_pressed(action: Action) -> impl FnMut(ActionInput) -> bool {
+    move |input: ActionInput| input.just_pressed(action)
+}
@@ -1,14 +1,29 @@
-use bevy::{
-    ecs::query::QueryFilter, input::common_conditions::input_just_pressed,
-    render::camera::ScalingMode,
-};
+use bevy::{ecs::query::QueryFilter, render::camera::ScalingMode};
 use bullet::AltFire;
-use enemy::{EnemyMarker, Health};
+use collision::{Circle, Collider, Shape};
+use enemy::{Boss, EnemyMarker, Health, MaxHealth};
+use input::TouhouInput;
+use pause::PauseState;
+
+pub use enemy::{SpellcardInfo, spellcards};
 
 use crate::prelude::*;
 
+mod bomb;
 mod bullet;
+mod charge;
+mod collision;
 mod enemy;
+mod familiar;
+mod input;
+mod items;
+mod pause;
+mod pool;
+mod replay;
+mod score;
+mod shield;
+mod stage;
+mod weapons;
 
 #[derive(Component, Clone, Default, Debug)]
 struct TouhouMarker;
@@ -24,58 +39,19 @@ struct PlayerFilter {
 
 type PlayerQ<'a, T> = Single<'a, T, With<PlayerMarker>>;
 
-#[derive(Component, Default, Copy, Clone, Debug)]
-struct Collider {
-    radius: f32,
-}
-
-impl Collider {
-    fn to_circle(&self, pos: Vec2) -> Circle {
-        let Self { radius } = *self;
-        Circle { pos, radius }
-    }
-
-    fn new(radius: f32) -> Self {
-        Self { radius }
-    }
-}
-
-#[derive(Default, Copy, Clone, Debug)]
-struct Circle {
-    pos: Vec2,
-    radius: f32,
-}
-
 #[derive(Resource, Default)]
 struct ShowGizmos {
     enabled: bool,
 }
 
-impl Circle {
-    fn new(radius: f32, pos: Vec2) -> Self {
-        Self { pos, radius }
-    }
-
-    #[allow(dead_code)]
-    fn within(&self, rect: Rect) -> bool {
-        let Self { pos, radius } = *self;
-
-        let bounding_rect = Rect::from_center_size(pos, Vec2::splat(radius));
-
-        rect.contains(bounding_rect.min) && rect.contains(bounding_rect.max)
-    }
-
-    fn hits(&self, other: Circle) -> bool {
-        (self.pos - other.pos).length() - (self.radius + other.radius) < 0.0
-    }
-}
-
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
 pub enum MissionState {
     #[default]
     Ongoing,
     Success,
     Fail,
+    /// Left through the pause menu, neither won nor lost.
+    Retreat,
 }
 
 #[derive(Resource, Default)]
@@ -89,6 +65,7 @@ enum TouhouSets {
     Gameplay,
 }
 
+/// Player movement speed in units/s.
 #[derive(Component, Deref, DerefMut, Default)]
 struct Speed(f32);
 
@@ -102,11 +79,30 @@ pub struct PlayerAssets {
 
 pub fn touhou_plugin(app: &mut App) {
     let touhou_gameplay_pred = || {
-        TouhouSets::Gameplay
-            .run_if(in_state(GameState::Touhou).and(in_state(MissionState::Ongoing)))
+        TouhouSets::Gameplay.run_if(
+            in_state(GameState::Touhou)
+                .and(in_state(MissionState::Ongoing))
+                .and(in_state(PauseState::Running)),
+        )
     };
 
     app.add_plugins((bullet::bullet_plugin, enemy::enemy_plugin))
+        .add_plugins((
+            pool::pool_plugin,
+            score::score_plugin,
+            bomb::bomb_plugin,
+            items::item_plugin,
+        ))
+        .add_plugins((
+            input::input_plugin,
+            replay::replay_plugin,
+            pause::pause_plugin,
+            stage::stage_plugin,
+            shield::shield_plugin,
+            weapons::weapons_plugin,
+            familiar::familiar_plugin,
+            charge::charge_plugin,
+        ))
         .init_state::<MissionState>()
         .insert_resource(ShowGizmos { enabled: false })
         .add_systems(
@@ -118,7 +114,9 @@ pub fn touhou_plugin(app: &mut App) {
             (
                 spawn_player,
                 make_bg,
-                bullet::config_loadout.after(spawn_player),
+                bullet::config_loadout
+                    .after(spawn_player)
+                    .after(score::reset_score),
                 make_game_camera,
                 set_mission_status,
                 play_music,
@@ -143,12 +141,12 @@ pub fn touhou_plugin(app: &mut App) {
         .add_systems(
             Update,
             (
-                scroll_map.run_if(in_state(GameState::Touhou)),
-                toggle_gizmos.run_if(input_just_pressed(KeyCode::Space)),
+                scroll_map.run_if(in_state(GameState::Touhou).and(in_state(PauseState::Running))),
+                toggle_gizmos.run_if(action_just_pressed(Action::Debug)),
                 animate_player,
             ),
         )
-        .add_systems(PostUpdate, draw_gizmos.in_set(TouhouSets::Gameplay))
+        .add_systems(PostUpdate, draw_gizmos.run_if(in_state(GameState::Touhou)))
         // set them all to only run if gamestate is touhou
         .configure_sets(FixedUpdate, touhou_gameplay_pred())
         .configure_sets(FixedPreUpdate, touhou_gameplay_pred())
@@ -228,16 +226,13 @@ fn spawn_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
 }
 
 fn update_hud(
-    player: Option<Single<(&Ammo, &Life), PlayerFilter>>,
-    enemy_hp: Option<Single<&Health, With<EnemyMarker>>>,
+    player: Option<Single<(&Ammo, &Life, &Bombs), PlayerFilter>>,
+    bosses: Query<(&Health, &MaxHealth), With<Boss>>,
     mut ammo_text: Query<&mut Text, (With<AmmoCount>, Without<LifeCount>)>,
     mut hp_text: Query<&mut Text, (With<LifeCount>, Without<AmmoCount>)>,
     mut hp_bar: Query<&mut Node, (With<HPBar>)>,
 ) {
-    let Some((ammo_count, lives_count)) = player.map(|x| x.into_inner()) else {
-        return;
-    };
-    let Some(enemy_hp) = enemy_hp else {
+    let Some((ammo_count, lives_count, bomb_count)) = player.map(|x| x.into_inner()) else {
         return;
     };
     for mut text in &mut ammo_text {
@@ -245,12 +240,22 @@ fn update_hud(
     }
 
     for mut text in &mut hp_text {
-        **text = format!["Lives: {}", **lives_count];
+        **text = format!["Lives: {}\nBombs: {}", **lives_count, **bomb_count];
     }
 
+    // the bar is for the boss, mooks get their own little ones
+    let (health, max) = bosses
+        .iter()
+        .fold((0, 0), |(h, m), (health, max)| (h + **health, m + **max));
+    let fraction = if max == 0 {
+        0.0
+    } else {
+        health as f32 / max as f32
+    };
+
     for mut node in &mut hp_bar {
         *node = Node {
-            width: Val::Vw(80. * (***enemy_hp as f32) / 4000.),
+            width: Val::Vw(80. * fraction),
             height: Val::Vh(10.0),
             left: Val::Vw(10.),
             bottom: -Val::Vh(90.),
@@ -294,10 +299,11 @@ fn enemy_dead(mut commands: Commands, enemies: Query<(Entity, &Health), With<Ene
 }
 
 fn last_enemy_dead(
+    stage: Res<stage::Stage>,
     enemies: Query<Entity, With<EnemyMarker>>,
     mut mission_state: ResMut<NextState<MissionState>>,
 ) {
-    if enemies.is_empty() {
+    if stage.boss_spawned && enemies.is_empty() {
         mission_state.set(MissionState::Success)
     }
 }
@@ -363,6 +369,7 @@ fn load_touhou_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
             None,
         )),
         rocket: asset_server.load("Xcom_hud/rocket2.png"),
+        beam: asset_server.load("bullets/Beam.png"),
 
         moongirl_bullet_sheet: asset_server.load("bullets/Moonbullet1-sheet.png"),
         moongirl_layout: asset_server.add(TextureAtlasLayout::from_grid(
@@ -399,6 +406,12 @@ fn load_touhou_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
             None,
             None,
         )),
+
+        item_ammo: asset_server.load("Xcom_hud/Ammo.png"),
+        item_bomb: asset_server.load("Xcom_hud/rocket.png"),
+        item_life: asset_server.load("Xcom_hud/Heart.png"),
+        item_dust: asset_server.load("Xcom_hud/Magic_bullet.png"),
+        familiar: asset_server.load("Xcom_hud/Flight.png"),
     })
 }
 
@@ -413,6 +426,7 @@ pub struct TouhouAssets {
     lizard_sheet: Handle<Image>,
     lizard_layout: Handle<TextureAtlasLayout>,
     rocket: Handle<Image>,
+    beam: Handle<Image>,
 
     moongirl_bullet_sheet: Handle<Image>,
     moongirl_layout: Handle<TextureAtlasLayout>,
@@ -425,6 +439,12 @@ pub struct TouhouAssets {
 
     girl_bullet2_sheet: Handle<Image>,
     girl_bullet2_layout: Handle<TextureAtlasLayout>,
+
+    item_ammo: Handle<Image>,
+    item_bomb: Handle<Image>,
+    item_life: Handle<Image>,
+    item_dust: Handle<Image>,
+    familiar: Handle<Image>,
 }
 
 fn player_dead(life: Option<PlayerQ<&Life>>) -> bool {
@@ -433,15 +453,23 @@ fn player_dead(life: Option<PlayerQ<&Life>>) -> bool {
 
 fn on_damage(
     mut commands: Commands,
-    player: Option<Single<(Entity, &mut Sprite), (PlayerFilter, Changed<Life>)>>,
+    mut lost: EventReader<bullet::LifeLost>,
+    player: Option<PlayerQ<(Entity, Option<&PilotPerks>)>>,
 ) {
-    let Some((ent, mut sprite)) = player.map(|p| p.into_inner()) else {
+    // picking up an extra life also changes Life, so go by the event instead
+    if lost.read().count() == 0 {
+        return;
+    }
+    let Some((ent, perks)) = player.map(|p| p.into_inner()) else {
         return;
     };
 
     commands
         .entity(ent)
-        .insert(Invulnerability(Timer::from_seconds(3.0, TimerMode::Once)));
+        .insert(Invulnerability(Timer::from_seconds(
+            PilotPerks::invulnerability(perks, 3.0),
+            TimerMode::Once,
+        )));
 }
 
 fn on_death(
@@ -545,16 +573,13 @@ fn animate_player(
     player_assets: Res<PlayerAssets>,
     mut animation_timer: Local<Option<Timer>>,
     mut inverted: Local<bool>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: Res<TouhouInput>,
 ) {
     let Some((mut sprite, invuln)) = player.map(Single::into_inner) else {
         return;
     };
 
-    let up = keyboard_input.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) as i32;
-    let down = keyboard_input.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) as i32;
-
-    let direction = up - down;
+    let direction = input.movement().y as i32;
 
     let atlas: &mut TextureAtlas = sprite.texture_atlas.as_mut().unwrap();
 
@@ -584,6 +609,31 @@ fn animate_player(
 #[derive(Component, Deref)]
 struct Invulnerability(Timer);
 
+/// How much longer [`Perk::CoolHead`] keeps the player untouchable after a hit.
+const COOL_HEAD_INVULNERABILITY: f32 = 1.5;
+/// The player's speed while focused, [`Perk::SteadyHands`] gets the better one.
+const FOCUS_SPEED: f32 = 0.5;
+const STEADY_HANDS_FOCUS_SPEED: f32 = 0.7;
+
+/// The perks of the pilot in the fight, see [`MissionParams::perks`].
+#[derive(Component, Default, Deref)]
+pub struct PilotPerks(pub Vec<Perk>);
+
+impl PilotPerks {
+    pub fn has(&self, perk: Perk) -> bool {
+        self.0.contains(&perk)
+    }
+
+    /// How long a hit keeps the player untouchable, given the usual time.
+    fn invulnerability(perks: Option<&Self>, secs: f32) -> f32 {
+        if perks.is_some_and(|perks| perks.has(Perk::CoolHead)) {
+            secs * COOL_HEAD_INVULNERABILITY
+        } else {
+            secs
+        }
+    }
+}
+
 #[derive(Bundle, Default)]
 pub struct Player {
     sprite: Sprite,
@@ -592,6 +642,7 @@ pub struct Player {
     lives: Life,
     markers: (PlayerMarker, TouhouMarker),
     ammo: Ammo,
+    bombs: Bombs,
     speed: Speed,
 }
 
@@ -601,6 +652,9 @@ pub struct Life(usize);
 #[derive(Component, Deref, DerefMut, Default)]
 pub struct Ammo(u32);
 
+#[derive(Component, Deref, DerefMut, Default)]
+pub struct Bombs(u32);
+
 impl Default for Life {
     fn default() -> Self {
         Life(1)
@@ -631,10 +685,12 @@ pub fn spawn_player(mut commands: Commands, player_assets: Res<PlayerAssets>) {
             ..Default::default()
         },
         transform: Transform::from_xyz(-1920.0 / 3.0, 0.0, -0.5),
-        collider: Collider { radius: 7.5 },
-        lives: Life(3),
-        speed: Speed(6.5),
-        ammo: Ammo(1000),
+        // config_loadout fills these in properly once the loadout is known
+        collider: Collider::new(ShipStats::BASE.hitbox),
+        lives: Life(ShipStats::BASE.lives as usize),
+        speed: Speed(ShipStats::BASE.speed),
+        ammo: Ammo(ShipStats::BASE.ammo as u32),
+        bombs: Bombs(ShipStats::BASE.bombs as u32),
         ..Default::default()
     });
 }
@@ -645,7 +701,7 @@ fn draw_gizmos(
     enabled: ResMut<ShowGizmos>,
     colliders: Query<(&Transform, &Collider)>,
 ) {
-    if enabled.enabled || true {
+    if !enabled.enabled {
         return;
     }
     use bevy::color::palettes::css::RED;
@@ -660,19 +716,14 @@ fn draw_gizmos(
     );
 
     for (trans, coll) in &colliders {
-        gizmos.circle_2d(
-            Isometry2d::from_translation(trans.translation.xy()),
-            coll.radius,
-            RED,
-        );
+        coll.shape(trans).draw(&mut gizmos, RED);
     }
 }
 
 fn do_movement(
     time: Res<Time>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input: Res<TouhouInput>,
     area: Res<GameplayRect>,
-    asset_server: ResMut<AssetServer>,
     mut player_info: Single<
         (
             &Speed,
@@ -680,32 +731,31 @@ fn do_movement(
             &Collider,
             &mut Sprite,
             Option<&AltFire>,
+            Option<&PilotPerks>,
         ),
         With<PlayerMarker>,
     >,
 ) {
-    let (speed, mut trans, mut collider, mut sprite, alt_fire) = player_info.into_inner();
-    let up = keyboard_input.any_pressed([KeyCode::KeyW, KeyCode::ArrowUp]) as i32 as f32;
-    let down = keyboard_input.any_pressed([KeyCode::KeyS, KeyCode::ArrowDown]) as i32 as f32;
-    let left = keyboard_input.any_pressed([KeyCode::KeyA, KeyCode::ArrowLeft]) as i32 as f32;
-    let right = keyboard_input.any_pressed([KeyCode::KeyD, KeyCode::ArrowRight]) as i32 as f32;
-
-    let dy = up + -down;
-    let dx = right + -left;
+    let (speed, mut trans, mut collider, mut sprite, alt_fire, perks) = player_info.into_inner();
+    let Vec2 { x: dx, y: dy } = input.movement();
 
     let mut speed = **speed;
 
     if alt_fire.is_some() {
-        speed = speed / 2.0;
+        speed *= if perks.is_some_and(|perks| perks.has(Perk::SteadyHands)) {
+            STEADY_HANDS_FOCUS_SPEED
+        } else {
+            FOCUS_SPEED
+        };
     }
 
-    let wishdir = Vec3::new(dx, dy, 0.0).normalize_or_zero() * speed;
+    let wishdir = Vec3::new(dx, dy, 0.0).normalize_or_zero() * speed * time.delta_secs();
 
     let new_pos = (trans.translation + wishdir).xy();
 
     let rect = area.rect;
 
-    let rv = Vec2::splat(collider.radius);
+    let rv = Vec2::splat(collider.bounding_radius());
 
     let new_pos_clamped = new_pos.clamp(rect.min + rv, rect.max - rv);
 
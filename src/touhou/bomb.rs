@@ -0,0 +1,154 @@
+use bevy::color::palettes::css::WHITE;
+use bullet::{EnemyBullets, LifeLost};
+use enemy::{EnemyMarker, Health};
+use input::{InputFrame, input_just_pressed};
+use items::ItemKind;
+use pool::BulletPool;
+use rand::Rng;
+use replay::{RngStream, TouhouRng};
+
+use super::*;
+
+const BOMB_RADIUS: f32 = 600.0;
+const BOMB_DURATION: f32 = 1.0;
+const BOMB_DAMAGE: u32 = 150;
+const BOMB_INVULNERABILITY: f32 = 3.0;
+// chance for each bullet cleared by a bomb to turn into magic dust
+const BOMB_DROP_CHANCE: f64 = 0.1;
+
+/// How long after being hit the player can still bomb to cancel the hit.
+pub const DEATHBOMB_WINDOW: f32 = 0.15;
+
+pub fn bomb_plugin(app: &mut App) {
+    app.add_event::<BombUsed>()
+        .add_systems(
+            FixedUpdate,
+            (
+                use_bomb.run_if(input_just_pressed(InputFrame::BOMB)),
+                expand_bombs,
+                expire_deathbomb,
+            )
+                .chain()
+                .in_set(TouhouSets::Gameplay),
+        )
+        .add_systems(Update, draw_bombs.run_if(in_state(GameState::Touhou)));
+}
+
+#[derive(Event)]
+pub struct BombUsed;
+
+/// The player has been hit and loses a life once this runs out, unless they
+/// bomb before that.
+#[derive(Component)]
+pub struct Deathbomb(pub Timer);
+
+/// A bomb going off around the player, clearing bullets in a growing circle.
+#[derive(Component)]
+struct ActiveBomb {
+    timer: Timer,
+}
+
+impl ActiveBomb {
+    fn radius(&self) -> f32 {
+        BOMB_RADIUS * self.timer.fraction()
+    }
+}
+
+fn use_bomb(
+    mut commands: Commands,
+    mut used: EventWriter<BombUsed>,
+    mut result: ResMut<MissionResult>,
+    player: Option<PlayerQ<(Entity, &mut Bombs, Has<ActiveBomb>)>>,
+    mut enemies: Query<&mut Health, With<EnemyMarker>>,
+) {
+    let Some((ent, mut bombs, active)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+
+    if **bombs == 0 || active {
+        return;
+    }
+
+    **bombs -= 1;
+
+    for mut health in &mut enemies {
+        let dealt = BOMB_DAMAGE.min(**health);
+        **health -= dealt;
+        result.damage_dealt += dealt;
+    }
+
+    commands.entity(ent).remove::<Deathbomb>().insert((
+        ActiveBomb {
+            timer: Timer::from_seconds(BOMB_DURATION, TimerMode::Once),
+        },
+        Invulnerability(Timer::from_seconds(BOMB_INVULNERABILITY, TimerMode::Once)),
+    ));
+
+    used.send(BombUsed);
+}
+
+fn expand_bombs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool: ResMut<BulletPool>,
+    mut rng: ResMut<TouhouRng>,
+    assets: Res<TouhouAssets>,
+    player: Option<Single<(Entity, &Transform, &mut ActiveBomb), PlayerFilter>>,
+    bullets: Query<(Entity, &Transform), EnemyBullets>,
+) {
+    let Some((ent, trans, mut bomb)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+
+    bomb.timer.tick(time.delta());
+
+    let pos = trans.translation.xy();
+    let radius = bomb.radius();
+
+    let rng = rng.stream(RngStream::Bomb);
+
+    for (bullet, b_trans) in &bullets {
+        let b_pos = b_trans.translation.xy();
+
+        if b_pos.distance(pos) < radius {
+            pool.release(&mut commands, bullet);
+
+            if rng.random_bool(BOMB_DROP_CHANCE) {
+                items::spawn_item(&mut commands, rng, &assets, ItemKind::MagicDust, b_pos);
+            }
+        }
+    }
+
+    if bomb.timer.finished() {
+        commands.entity(ent).remove::<ActiveBomb>();
+    }
+}
+
+fn expire_deathbomb(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut lost: EventWriter<LifeLost>,
+    player: Option<Single<(Entity, &mut Life, &mut Deathbomb), PlayerFilter>>,
+) {
+    let Some((ent, mut life, mut window)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+
+    window.0.tick(time.delta());
+
+    if window.0.finished() {
+        life.0 = life.0.saturating_sub(1);
+        lost.send(LifeLost);
+        commands.entity(ent).remove::<Deathbomb>();
+    }
+}
+
+fn draw_bombs(mut gizmos: Gizmos, bombs: Query<(&Transform, &ActiveBomb)>) {
+    for (trans, bomb) in &bombs {
+        gizmos.circle_2d(
+            Isometry2d::from_translation(trans.translation.xy()),
+            bomb.radius(),
+            WHITE,
+        );
+    }
+}
@@ -1,16 +1,14 @@
-use std::{
-    collections::HashMap,
-    f32::consts::{PI, TAU},
-    time::Duration,
-};
+use std::collections::HashMap;
 
 use bevy::{
     color::palettes::css::{BLUE, RED},
     ecs::query::QueryFilter,
-    input::common_conditions::input_pressed,
     time::Stopwatch,
 };
+use bomb::Deathbomb;
 use enemy::{AnimatedSprite, BulletSpawner, EnemyMarker, Health};
+use input::{InputFrame, TouhouInput, input_pressed};
+use pool::BulletPool;
 
 use super::*;
 
@@ -20,16 +18,43 @@ pub struct PlayerBullets {
     cond: With<PlayerBullet>,
 }
 #[derive(QueryFilter)]
-struct EnemyBullets {
+pub struct EnemyBullets {
     marker: With<BulletMarker>,
     cond: Without<PlayerBullet>,
 }
 type Bullets = With<BulletMarker>;
 
+/// How far outside the [`GameplayRect`] a bullet may travel before it's culled.
+#[derive(Resource)]
+pub struct BulletBounds {
+    pub margin: f32,
+}
+
+impl Default for BulletBounds {
+    fn default() -> Self {
+        // the moon girl spawns rings of bullets ~1100 units out from her, so
+        // anything much tighter than this eats them before they're on screen
+        Self { margin: 600.0 }
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct BulletCounters {
+    pub out_of_bounds: usize,
+    pub expired: usize,
+}
+
 pub fn bullet_plugin(app: &mut App) {
-    app.add_event::<BulletHit>()
+    app.init_resource::<BulletBounds>()
+        .init_resource::<BulletCounters>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            |mut counters: ResMut<BulletCounters>| *counters = default(),
+        )
+        .add_event::<BulletHit>()
         .add_event::<PlayerHit>()
         .add_event::<EnemyHit>()
+        .add_event::<LifeLost>()
         .add_systems(
             FixedUpdate,
             (
@@ -40,87 +65,39 @@ pub fn bullet_plugin(app: &mut App) {
                     move_wave_bullets,
                     move_stutter_bullets,
                     resolve_delayed_bullets,
+                    update_lasers,
                 )
                     .chain(),
-                check_enemy_bullets,
+                check_enemy_bullets.after(update_lasers),
                 check_bullet_bullet,
-                check_player_bullets,
+                check_player_bullets.after(update_lasers),
                 despawn_bullets,
-                fire_weapons.run_if(input_pressed(KeyCode::KeyZ)),
+                fire_weapons.run_if(input_pressed(InputFrame::FIRE)),
                 tick_bullets,
             )
-                .run_if(in_state(GameState::Touhou)),
+                .in_set(TouhouSets::Gameplay),
         )
         .add_systems(
             FixedPreUpdate,
-            set_alt_fire.run_if(in_state(GameState::Touhou)),
+            set_alt_fire
+                .after(replay::sample_input)
+                .in_set(TouhouSets::Gameplay),
         )
         .add_systems(
             FixedPostUpdate,
             (bullet_bullet_hit, process_player_hits, process_enemy_hits)
-                .run_if(in_state(GameState::Touhou)),
+                .in_set(TouhouSets::Gameplay),
         );
 }
 
-fn make_machinegun(assets: &TouhouAssets) -> Weapon {
-    Weapon {
-        timer: Timer::new(Duration::from_secs_f32(0.05), TimerMode::Repeating),
-        ammo_cost: 1,
-        bullet: BulletSpawner::new(BulletBundle {
-            transform: Transform::from_xyz(0.0, 0.0, 0.0)
-                .with_rotation(Quat::from_rotation_z(PI / 2.0)),
-            collider: Collider { radius: 6.0 },
-            sprite: Sprite {
-                image: assets.bullet1.clone(),
-                ..Default::default()
-            },
-            ..Default::default()
-        })
-        .normal(Vec2::new(20.0, 0.0)),
-        salted: true,
-        damage: 2,
-        phasing: false,
-    }
-}
-
-fn make_rocketlauncher(assets: &TouhouAssets) -> Weapon {
-    let bundle = BulletBundle {
-        transform: Transform::from_xyz(0.0, 0.0, 0.0),
-        collider: Collider { radius: 20.0 },
-        sprite: Sprite {
-            image: assets.rocket.clone(),
-            custom_size: Some(Vec2::new(100.0, 100.0)),
-            ..Default::default()
-        },
-        ..Default::default()
-    };
-
-    Weapon {
-        timer: Timer::new(Duration::from_secs_f32(0.5), TimerMode::Repeating),
-        ammo_cost: 100,
-        bullet: BulletSpawner::new(bundle.clone())
-            .normal(Vec2 { x: 10.0, y: 0.0 })
-            .delayed(DelayedBullet {
-                bullet: BulletSpawner::new(bundle)
-                    .normal(Vec2::new(10.0, 0.0))
-                    .homing(60.0, TAU / 2.0, Target::Enemy),
-                delay: 0.3,
-                deployed: false,
-            }),
-        salted: false,
-        damage: 50,
-        phasing: false,
-    }
-}
-
 #[derive(Component)]
 pub struct Weapon {
-    timer: Timer,
-    ammo_cost: u32,
-    bullet: BulletSpawner,
-    salted: bool,
-    phasing: bool,
-    damage: u32,
+    pub timer: Timer,
+    pub ammo_cost: u32,
+    pub bullet: BulletSpawner,
+    pub salted: bool,
+    pub phasing: bool,
+    pub damage: u32,
 }
 
 #[derive(Clone, Debug)]
@@ -131,18 +108,45 @@ pub(crate) enum BulletType {
     Stutter(StutterBullet),
     Wave(WaveBullet),
     Delayed(DelayedBullet),
+    Laser(LaserBullet),
 }
 
+/// The parts of the player ship the loadout decides.
+type LoadoutTarget = (
+    Entity,
+    &'static mut Speed,
+    &'static mut Ammo,
+    &'static mut Life,
+    &'static mut Bombs,
+    &'static mut Collider,
+);
+
 pub fn config_loadout(
     mission_params: Res<MissionParams>,
+    mut result: ResMut<MissionResult>,
     mut commands: Commands,
     assets: Res<TouhouAssets>,
-    player: Single<(Entity, &mut Speed, &mut Ammo, &mut Life, &mut Collider), With<PlayerMarker>>,
+    weapon_defs: Res<weapons::WeaponDefs>,
+    player: PlayerQ<LoadoutTarget>,
 ) {
     let loadout = &mission_params.loadout;
-    let (ent, mut speed, mut ammo, mut life, mut collider) = player.into_inner();
+    let (ent, mut speed, mut ammo, mut life, mut bombs, mut collider) = player.into_inner();
     let assets = &*assets;
 
+    let stats = ShipStats::for_ship(
+        mission_params.hull,
+        loadout.iter().map(|&(tech, _)| tech),
+        &mission_params.perks,
+    );
+
+    **speed = stats.speed;
+    **life = stats.lives as usize;
+    **bombs = stats.bombs as u32;
+    *collider = Collider::new(stats.hitbox);
+    // the loadout only decides how much fits, the rounds come out of stock
+    **ammo = (stats.ammo as u32).min(mission_params.ammo);
+    result.ammo_loaded = **ammo;
+
     let mut weapons = vec![];
     let mut alt_weapons = vec![];
 
@@ -152,24 +156,32 @@ pub fn config_loadout(
     let mut phasing = false;
     let mut alt_phasing = false;
 
-    let mut ammo_multiplier = 1.0;
-    let mut damage_multiplier = 1.0;
+    let mut familiars = 0;
+    // which groups they're equipped in, for the bullet effects they get
+    let mut main_familiars = false;
+    let mut alt_familiars = false;
 
     for &(tech, alt) in loadout {
-        let mut weapon_vec =
-            |alt, weapon| if alt { &mut alt_weapons } else { &mut weapons }.push(weapon);
+        // their guns go on the familiars, not the ship
+        if tech == Tech::Familiars {
+            familiars += familiar::FAMILIARS_PER_TECH;
+            if alt {
+                alt_familiars = true;
+            } else {
+                main_familiars = true;
+            }
+            continue;
+        }
+
+        let side = if alt { &mut alt_weapons } else { &mut weapons };
+        side.extend(
+            weapon_defs
+                .get(tech)
+                .iter()
+                .map(|def| (def.build(assets).tuned(&stats), def.charge())),
+        );
 
         match tech {
-            Tech::MachineGun => weapon_vec(alt, make_machinegun(assets)),
-            Tech::AmmoStockpile => **ammo += 1000,
-            Tech::HeavyBody => {
-                ammo_multiplier += 0.5;
-                **life += 3;
-                collider.radius += 5.0
-            }
-            Tech::Rocket => {
-                weapon_vec(alt, make_rocketlauncher(assets));
-            }
             Tech::MagicBullet => {
                 if alt {
                     alt_salted = true;
@@ -177,15 +189,6 @@ pub fn config_loadout(
                     salted = true;
                 }
             }
-            Tech::EngineT1 => **speed *= 2.0,
-            Tech::EngineT2 => {
-                **speed *= 4.0;
-                damage_multiplier += 0.5;
-            }
-            Tech::MachineGunT2 => {
-                weapon_vec(alt, make_machinegun(assets));
-                weapon_vec(alt, make_machinegun(assets));
-            }
             Tech::Phase => {
                 if alt {
                     alt_phasing = true;
@@ -197,22 +200,61 @@ pub fn config_loadout(
         }
     }
 
-    **ammo = (**ammo as f32 * ammo_multiplier) as u32;
+    if stats.shield > 0.0 {
+        commands
+            .entity(ent)
+            .insert(shield::Shield::new(stats.shield as u32, stats.shield_regen));
+    }
+
+    commands
+        .entity(ent)
+        .insert(PilotPerks(mission_params.perks.clone()));
 
     commands.entity(ent).with_children(|player| {
-        for mut weapon in weapons {
-            weapon.salted = salted;
-            player.spawn(weapon);
+        // magic bullet and phase rounds go on top of whatever the gun fires anyway
+        for (mut weapon, charge) in weapons {
+            weapon.salted |= salted;
+            weapon.phasing |= phasing;
+            let mut weapon = player.spawn(weapon);
+            if let Some(charge) = charge {
+                weapon.insert(charge);
+            }
         }
-        for mut weapon in alt_weapons {
-            weapon.salted = alt_salted;
-            player.spawn(weapon).insert(AltFire);
+        for (mut weapon, charge) in alt_weapons {
+            weapon.salted |= alt_salted;
+            weapon.phasing |= alt_phasing;
+            let mut weapon = player.spawn(weapon);
+            weapon.insert(AltFire);
+            if let Some(charge) = charge {
+                weapon.insert(charge);
+            }
         }
+
+        familiar::spawn_familiars(
+            player,
+            assets,
+            weapon_defs.get(Tech::Familiars),
+            familiars,
+            &stats,
+            (main_familiars && salted) || (alt_familiars && alt_salted),
+            (main_familiars && phasing) || (alt_familiars && alt_phasing),
+        );
     });
 }
 
 impl Weapon {
-    fn spawn_bullet(&mut self, commands: &mut Commands, player_pos: Vec2) {
+    /// Applies the ship's damage to the gun.
+    pub fn tuned(mut self, stats: &ShipStats) -> Self {
+        self.damage = (self.damage as f32 * stats.damage).round() as u32;
+        self
+    }
+
+    pub fn spawn_bullet(
+        &mut self,
+        commands: &mut Commands,
+        pool: &mut BulletPool,
+        player_pos: Vec2,
+    ) {
         self.timer.reset();
 
         let bullet = BulletBundle {
@@ -223,7 +265,7 @@ impl Weapon {
             ..self.bullet.bullet.clone()
         };
 
-        let mut ent = commands.spawn(bullet);
+        let mut ent = pool.spawn(commands, bullet);
 
         ent.insert(PlayerBullet {
             damage: self.damage,
@@ -239,26 +281,31 @@ fn set_alt_fire(
     mut commands: Commands,
     player: PlayerQ<Entity>,
     mut weapons: Query<&mut Weapon>,
-    input: Res<ButtonInput<KeyCode>>,
+    input: Res<TouhouInput>,
 ) {
     let mut player = commands.entity(*player);
 
-    if input.just_pressed(KeyCode::ShiftLeft) || input.just_released(KeyCode::ShiftLeft) {
+    if input.just_pressed(InputFrame::FOCUS) || input.just_released(InputFrame::FOCUS) {
         for mut weapon in &mut weapons {
             weapon.timer.reset();
         }
     }
-    if input.pressed(KeyCode::ShiftLeft) {
+    if input.pressed(InputFrame::FOCUS) {
         player.insert(AltFire);
     } else {
         player.remove::<AltFire>();
     }
 }
 
+// familiars fire from wherever they are, see familiar::fire_familiars, and
+// charge weapons only fire when let go, see charge::charge_weapons
+type ShipGuns = (Without<familiar::Familiar>, Without<charge::Charge>);
+
 fn fire_weapons(
     time: Res<Time>,
     mut commands: Commands,
-    mut weapons: Query<(&mut Weapon, Option<&AltFire>)>,
+    mut pool: ResMut<BulletPool>,
+    mut weapons: Query<(&mut Weapon, Option<&AltFire>), ShipGuns>,
     player: PlayerQ<(&Transform, &mut Ammo, Option<&AltFire>)>,
 ) {
     let (trans, mut ammo, alt) = player.into_inner();
@@ -299,6 +346,7 @@ fn fire_weapons(
 
             weapon.spawn_bullet(
                 &mut commands,
+                &mut pool,
                 pos - Vec2 {
                     x: 0.0,
                     y: (((weapon_idx - 1) * 50) - (25 * (weapon_count - 1))) as f32,
@@ -323,7 +371,28 @@ pub struct BulletBundle {
 }
 
 #[derive(Component, Default, Clone, Debug)]
-pub struct Lifetime(Stopwatch);
+pub struct Lifetime {
+    watch: Stopwatch,
+    // bullets older than this get culled even if they're still on screen
+    max: Option<f32>,
+}
+
+impl Lifetime {
+    pub fn with_max(max: f32) -> Self {
+        Self {
+            watch: Stopwatch::new(),
+            max: Some(max),
+        }
+    }
+
+    pub fn elapsed_secs(&self) -> f32 {
+        self.watch.elapsed_secs()
+    }
+
+    fn expired(&self) -> bool {
+        self.max.is_some_and(|max| self.elapsed_secs() >= max)
+    }
+}
 
 #[derive(Component, Deref, DerefMut, Default)]
 pub struct PlayerBullet {
@@ -332,6 +401,7 @@ pub struct PlayerBullet {
 
 #[derive(Component, Clone, Copy, Default, Debug)]
 pub struct NormalBullet {
+    /// in units/s
     pub velocity: Vec2,
 }
 
@@ -374,10 +444,48 @@ pub struct RotatingBullet {
     pub origin: Vec2,
     // rotation speed in radians/s
     pub rotation_speed: f32,
+    pub track_emitter: bool,
+    /// the emitter being circled and the origin's offset from it, for
+    /// bullets that track their emitter
+    pub anchor: Option<(Entity, Vec2)>,
 }
 
-fn circle(t: &Transform, c: &Collider) -> Circle {
-    super::Circle::new(c.radius, t.translation.xy())
+// how wide the warning line is drawn before a laser goes live
+const LASER_WARNING_WIDTH: f32 = 3.0;
+// lasers that keep touching something hurt it this often, in seconds
+const LASER_HIT_INTERVAL: f32 = 0.1;
+
+/// A beam from the bullet's position along its rotation. For the first
+/// `warning` seconds it's only a thin line that doesn't hurt anything.
+#[derive(Component, Clone, Default, Debug)]
+pub struct LaserBullet {
+    pub length: f32,
+    pub width: f32,
+    pub warning: f32,
+    // rotation speed in radians/s
+    pub rotation_speed: f32,
+    hit_timer: Timer,
+}
+
+impl LaserBullet {
+    pub fn new(length: f32, width: f32, warning: f32, rotation_speed: f32) -> Self {
+        Self {
+            length,
+            width,
+            warning,
+            rotation_speed,
+            hit_timer: Timer::from_seconds(LASER_HIT_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    pub fn active(&self, lifetime: &Lifetime) -> bool {
+        lifetime.elapsed_secs() >= self.warning
+    }
+
+    /// Covers the beam, from the bullet's position out along its rotation.
+    pub fn collider(&self) -> Collider {
+        Collider::capsule(Vec2::ZERO, Vec2::new(self.length, 0.0), self.width / 2.0)
+    }
 }
 
 #[derive(Component, Default)]
@@ -389,6 +497,11 @@ pub struct Phasing;
 #[derive(Event)]
 pub struct PlayerHit(Entity);
 
+/// Sent whenever the player actually loses a life, as opposed to [`PlayerHit`]
+/// which also fires for bullets hitting an invulnerable player.
+#[derive(Event)]
+pub struct LifeLost;
+
 #[derive(Debug, Clone, Component, Default)]
 pub struct Velocity {
     velocity: Vec2,
@@ -404,19 +517,21 @@ pub struct BulletHit {
 }
 
 #[derive(Event, Copy, Clone)]
-struct EnemyHit {
+pub struct EnemyHit {
     bullet: Entity,
     enemy: Entity,
 }
 
 pub fn process_enemy_hits(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     mut hits: EventReader<EnemyHit>,
-    player_bullets: Query<(&PlayerBullet, Option<&Phasing>)>,
+    mut result: ResMut<MissionResult>,
+    player_bullets: Query<(&PlayerBullet, Option<&Phasing>, Has<LaserBullet>)>,
     mut enemies: Query<&mut Health, With<EnemyMarker>>,
 ) {
     for &EnemyHit { enemy, bullet } in hits.read() {
-        let Ok((damage, phasing)) = player_bullets.get(bullet) else {
+        let Ok((damage, phasing, laser)) = player_bullets.get(bullet) else {
             continue;
         };
 
@@ -424,21 +539,38 @@ pub fn process_enemy_hits(
             continue;
         };
 
-        **enemy_health =
-            enemy_health.saturating_sub(**damage * if phasing.is_some() { 1 } else { 2 });
+        let dealt = (**damage * if phasing.is_some() { 1 } else { 2 }).min(**enemy_health);
+        **enemy_health -= dealt;
+
+        result.damage_dealt += dealt;
+        result.score += dealt as u64 * score::DAMAGE_POINTS;
 
-        commands.entity(bullet).try_despawn();
+        // lasers go through and keep hitting until they run out
+        if !laser {
+            pool.release(&mut commands, bullet);
+        }
     }
 }
 
+/// Everything on the player that decides what a hit does to it.
+type HitTaker = (
+    Entity,
+    Option<&'static Invulnerability>,
+    Has<Deathbomb>,
+    Option<&'static mut shield::Shield>,
+    Option<&'static PilotPerks>,
+);
+
 pub fn process_player_hits(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     mut hits: EventReader<PlayerHit>,
     mut bullet_hits: EventReader<BulletHit>,
     salted_bullets: Query<Entity, (PlayerBullets, With<Salted>)>,
-    player: Option<PlayerQ<(&mut Life, Option<&Invulnerability>)>>,
+    lasers: Query<(), With<LaserBullet>>,
+    player: Option<PlayerQ<HitTaker>>,
 ) {
-    let Some((mut life, immortal)) = player.map(|p| p.into_inner()) else {
+    let Some((player, immortal, dying, mut shield, perks)) = player.map(|p| p.into_inner()) else {
         return;
     };
 
@@ -447,7 +579,7 @@ pub fn process_player_hits(
         .map(|hit| (hit.enemy, hit.player))
         .collect();
 
-    let mut life_lost = false;
+    let mut hit_taken = false;
     for PlayerHit(ent) in hits.read() {
         // if something else already hit this bullet
         if let Some(&player_bullet) = already_collided_bullets.get(&ent) {
@@ -458,23 +590,42 @@ pub fn process_player_hits(
         }
 
         // try to get the bullet entity, but if it has already despawned, continue
-        let Some(mut bullet) = commands.get_entity(*ent) else {
+        if commands.get_entity(*ent).is_none() {
             continue;
-        };
+        }
 
-        bullet.try_despawn();
+        if !lasers.contains(*ent) {
+            pool.release(&mut commands, *ent);
+        }
 
-        if life_lost || immortal.is_some() {
+        if hit_taken || immortal.is_some() || dying {
+            continue;
+        }
+        hit_taken = true;
+
+        if shield.as_mut().is_some_and(|shield| shield.absorb()) {
+            commands
+                .entity(player)
+                .insert(Invulnerability(Timer::from_seconds(
+                    PilotPerks::invulnerability(perks, shield::SHIELD_BREAK_INVULNERABILITY),
+                    TimerMode::Once,
+                )));
             continue;
         }
 
-        life.0 = life.0.saturating_sub(1);
-        life_lost = true;
+        // the life is only taken once the deathbomb window runs out
+        commands
+            .entity(player)
+            .insert(Deathbomb(Timer::from_seconds(
+                bomb::DEATHBOMB_WINDOW,
+                TimerMode::Once,
+            )));
     }
 }
 
 fn bullet_bullet_hit(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     mut hits: EventReader<BulletHit>,
     player_bullets: Query<(&NormalBullet, Option<&Salted>), PlayerBullets>,
     enemy_bullets: Query<&NormalBullet, EnemyBullets>,
@@ -487,42 +638,54 @@ fn bullet_bullet_hit(
             continue;
         };
 
-        commands.entity(*player).despawn();
+        pool.release(&mut commands, *player);
         if salted.is_some() {
-            commands.entity(*enemy).despawn();
+            pool.release(&mut commands, *enemy);
         }
     }
 }
 
 fn check_player_bullets(
     mut hits: EventWriter<EnemyHit>,
-    player_bullets: Query<(Entity, &Transform, &Collider), PlayerBullets>,
+    player_bullets: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            &Lifetime,
+            Option<&LaserBullet>,
+        ),
+        PlayerBullets,
+    >,
     enemies: Query<(Entity, &Transform, &Collider), With<enemy::EnemyMarker>>,
 ) {
-    for (bullet, b_trans, b_coll) in &player_bullets {
-        let b_circle = b_coll.to_circle(b_trans.translation.xy());
-        for (enemy, e_trans, e_coll) in &enemies {
-            let e_circle = e_coll.to_circle(e_trans.translation.xy());
+    for (bullet, b_trans, b_coll, lifetime, laser) in &player_bullets {
+        // lasers only hurt once they're live, and then once per hit interval
+        if laser.is_some_and(|l| !l.active(lifetime) || !l.hit_timer.just_finished()) {
+            continue;
+        }
 
-            if b_circle.hits(e_circle) {
+        let b_shape = b_coll.shape(b_trans);
+        for (enemy, e_trans, e_coll) in &enemies {
+            if b_shape.hits(&e_coll.shape(e_trans)) {
                 hits.send(EnemyHit { enemy, bullet });
             }
         }
     }
 }
 
+type BulletShape = (Entity, &'static Transform, &'static Collider);
+
 fn check_bullet_bullet(
     mut hits: EventWriter<BulletHit>,
-    player_bullets: Query<(Entity, &Transform, &Collider), (PlayerBullets, Without<Phasing>)>,
-    enemy_bullets: Query<(Entity, &Transform, &Collider), EnemyBullets>,
+    player_bullets: Query<BulletShape, (PlayerBullets, Without<Phasing>, Without<LaserBullet>)>,
+    enemy_bullets: Query<BulletShape, (EnemyBullets, Without<LaserBullet>)>,
 ) {
     for (p, p_trans, p_coll) in &player_bullets {
-        let player_circle = circle(p_trans, p_coll);
+        let player_shape = p_coll.shape(p_trans);
 
         for (e, e_trans, e_coll) in &enemy_bullets {
-            let enemy_circle = circle(e_trans, e_coll);
-
-            if player_circle.hits(enemy_circle) {
+            if player_shape.hits(&e_coll.shape(e_trans)) {
                 hits.send(BulletHit {
                     player: p,
                     enemy: e,
@@ -534,18 +697,29 @@ fn check_bullet_bullet(
 
 fn check_enemy_bullets(
     player: PlayerQ<(&Transform, &Collider)>,
-    bullet_query: Query<(Entity, &Transform, &Collider), EnemyBullets>,
+    bullet_query: Query<
+        (
+            Entity,
+            &Transform,
+            &Collider,
+            &Lifetime,
+            Option<&LaserBullet>,
+        ),
+        EnemyBullets,
+    >,
     mut hit_writer: EventWriter<PlayerHit>,
 ) {
-    let player_circle = {
+    let player_shape = {
         let (trans, coll) = player.into_inner();
-        circle(trans, coll)
+        coll.shape(trans)
     };
 
-    for (ent, trans, coll) in &bullet_query {
-        let bullet_circle = circle(trans, coll);
+    for (ent, trans, coll, lifetime, laser) in &bullet_query {
+        if laser.is_some_and(|l| !l.active(lifetime)) {
+            continue;
+        }
 
-        if bullet_circle.hits(player_circle) {
+        if coll.shape(trans).hits(&player_shape) {
             hit_writer.send(PlayerHit(ent));
         }
     }
@@ -553,27 +727,51 @@ fn check_enemy_bullets(
 
 fn despawn_bullets(
     mut commands: Commands,
-    bullet_query: Query<(Entity, &Transform), EnemyBullets>,
+    mut pool: ResMut<BulletPool>,
+    mut counters: ResMut<BulletCounters>,
+    area: Res<GameplayRect>,
+    bounds: Res<BulletBounds>,
+    bullet_query: Query<(Entity, &Transform, &Lifetime), Bullets>,
 ) {
-    for (entity, transform) in &bullet_query {
-        if !Rect::new(-1500.0, -1500.0, 1500.0, 1500.0).contains(transform.translation.xy()) {
-            commands.entity(entity).despawn()
+    let rect = area.rect.inflate(bounds.margin);
+
+    for (entity, transform, lifetime) in &bullet_query {
+        if !rect.contains(transform.translation.xy()) {
+            counters.out_of_bounds += 1;
+            pool.release(&mut commands, entity);
+        } else if lifetime.expired() {
+            counters.expired += 1;
+            pool.release(&mut commands, entity);
         }
     }
 }
 
-fn move_normal_bullets(mut bullet_query: Query<(&NormalBullet, &mut Transform)>) {
+fn move_normal_bullets(time: Res<Time>, mut bullet_query: Query<(&NormalBullet, &mut Transform)>) {
     for (bullet, mut transform) in &mut bullet_query {
-        transform.translation += bullet.velocity.extend(0.0);
+        transform.translation += (bullet.velocity * time.delta_secs()).extend(0.0);
     }
 }
 
 fn move_rotating_bullets(
     time: Res<Time>,
-    mut bullet_query: Query<(&RotatingBullet, Option<&mut NormalBullet>, &mut Transform)>,
+    mut bullet_query: Query<(
+        &mut RotatingBullet,
+        Option<&mut NormalBullet>,
+        &mut Transform,
+    )>,
+    anchors: Query<&GlobalTransform>,
     mut gizmos: Gizmos,
 ) {
-    for (bullet, normal, mut trans) in &mut bullet_query {
+    for (mut bullet, normal, mut trans) in &mut bullet_query {
+        // carry the whole ring along with whatever it's circling
+        if let Some((anchor, offset)) = bullet.anchor
+            && let Ok(anchor) = anchors.get(anchor)
+        {
+            let origin = anchor.translation().xy() + offset;
+            trans.translation += (origin - bullet.origin).extend(0.0);
+            bullet.origin = origin;
+        }
+
         let prev_pos = trans.translation.xy();
 
         let pos_mod = prev_pos - bullet.origin;
@@ -608,26 +806,27 @@ fn move_homing_bullets(
         With<BulletMarker>,
     >,
     player: Option<Single<&Transform, (PlayerFilter, Without<BulletMarker>)>>,
-    enemy: Option<Single<&Transform, (With<EnemyMarker>, Without<BulletMarker>)>>,
+    enemies: Query<&Transform, (With<EnemyMarker>, Without<BulletMarker>)>,
     mut gizmos: Gizmos,
 ) {
-    let enemy = enemy.map(|e| e.translation);
-    let player = player.map(|p| p.translation);
+    let player = player.map(|p| p.translation.xy());
 
     for (mut sprite, bullet, mut normal, mut velocity, lifetime, mut trans) in &mut bullet_query {
-        if bullet.target == Target::Enemy && enemy.is_none() {
-            log::error!("can't find enemy");
-        }
-
-        let target_pos = match (bullet.target, player, enemy) {
-            (Target::Player, Some(player), _) => player,
-            (Target::Enemy, _, Some(enemy)) => enemy,
-            _ => continue,
-        }
-        .xy();
+        let pos = trans.translation.xy();
+
+        let target_pos = match bullet.target {
+            Target::Player => player,
+            // with a whole wave on screen, go for whichever is closest
+            Target::Enemy => enemies
+                .iter()
+                .map(|e| e.translation.xy())
+                .min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos))),
+        };
+        let Some(target_pos) = target_pos else {
+            continue;
+        };
 
-        if lifetime.0.elapsed_secs() <= bullet.seeking_time
-            && normal.velocity.length().abs() >= 0.01
+        if lifetime.elapsed_secs() <= bullet.seeking_time && normal.velocity.length().abs() >= 0.01
         {
             let angle =
                 (target_pos - trans.translation.xy()).normalize() * normal.velocity.length();
@@ -651,7 +850,7 @@ fn move_stutter_bullets(
     )>,
 ) {
     for (mut bullet, mut velocity, lifetime, mut trans) in &mut bullet_query {
-        if lifetime.0.elapsed_secs() < bullet.wait_time {
+        if lifetime.elapsed_secs() < bullet.wait_time {
             velocity.velocity = Vec2::ZERO;
         } else if !bullet.has_started {
             velocity.velocity = bullet.initial_velocity;
@@ -671,7 +870,7 @@ fn resolve_delayed_bullets(
     )>,
 ) {
     for (entity, mut bullet, mut velocity, lifetime, mut trans) in &mut bullet_query {
-        if lifetime.0.elapsed_secs() >= bullet.delay && !bullet.deployed {
+        if lifetime.elapsed_secs() >= bullet.delay && !bullet.deployed {
             bullet.deployed = true;
             bullet
                 .bullet
@@ -692,13 +891,36 @@ fn move_wave_bullets(
 ) {
     for (bullet, mut velocity, lifetime, mut trans, mut normal) in &mut bullet_query {
         normal.velocity =
-            bullet.true_velocity * ((lifetime.0.elapsed_secs() * bullet.sine_mod).sin() + 1.0);
+            bullet.true_velocity * ((lifetime.elapsed_secs() * bullet.sine_mod).sin() + 1.0);
+    }
+}
+
+fn update_lasers(
+    time: Res<Time>,
+    mut lasers: Query<(&mut LaserBullet, &Lifetime, &mut Transform, &mut Sprite)>,
+) {
+    for (mut laser, lifetime, mut trans, mut sprite) in &mut lasers {
+        trans.rotate_z(laser.rotation_speed * time.delta_secs());
+
+        let active = laser.active(lifetime);
+
+        if active {
+            laser.hit_timer.tick(time.delta());
+        }
+
+        let width = if active {
+            laser.width
+        } else {
+            LASER_WARNING_WIDTH
+        };
+        sprite.custom_size = Some(Vec2::new(laser.length, width));
+        sprite.color = Color::WHITE.with_alpha(if active { 1.0 } else { 0.4 });
     }
 }
 
 fn tick_bullets(time: Res<Time>, mut bullets: Query<&mut Lifetime, Bullets>) {
     for mut watch in &mut bullets {
-        watch.0.tick(time.delta());
+        watch.watch.tick(time.delta());
     }
 }
 
@@ -746,6 +968,12 @@ impl AsBulletKind for DelayedBullet {
     }
 }
 
+impl AsBulletKind for LaserBullet {
+    fn as_bullet_type(self) -> BulletType {
+        BulletType::Laser(self)
+    }
+}
+
 impl AsBulletKind for BulletType {
     fn as_bullet_type(self) -> BulletType {
         self
@@ -763,6 +991,118 @@ impl BulletCommandExt for EntityCommands<'_> {
             BulletType::Stutter(stutter) => self.insert(stutter),
             BulletType::Wave(wave) => self.insert(wave),
             BulletType::Delayed(delayed) => self.insert(delayed),
+            BulletType::Laser(laser) => self.insert(laser),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    /// Runs the movement systems for one simulated second at `hz` ticks a
+    /// second and returns where the player and each bullet ended up.
+    fn simulate(hz: u32) -> Vec<Vec2> {
+        let mut app = App::new();
+        app.init_gizmo_group::<DefaultGizmoConfigGroup>()
+            .init_resource::<Time>()
+            .init_resource::<TouhouInput>()
+            .insert_resource(GameplayRect {
+                rect: Rect::new(-2000.0, -2000.0, 2000.0, 2000.0),
+            })
+            .add_systems(
+                Update,
+                (
+                    move_normal_bullets,
+                    move_rotating_bullets,
+                    move_homing_bullets,
+                    do_movement,
+                    tick_bullets,
+                )
+                    .chain(),
+            );
+
+        app.world_mut()
+            .resource_mut::<TouhouInput>()
+            .advance(InputFrame(InputFrame::UP | InputFrame::RIGHT));
+
+        let world = app.world_mut();
+        world.spawn((EnemyMarker, Transform::from_xyz(0.0, 800.0, 0.0)));
+
+        let player = world
+            .spawn((
+                PlayerMarker,
+                Speed(416.0),
+                Collider::new(7.5),
+                Sprite::default(),
+                Transform::default(),
+            ))
+            .id();
+        let straight = world
+            .spawn((
+                BulletMarker,
+                Lifetime::default(),
+                NormalBullet {
+                    velocity: Vec2::new(300.0, -120.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+        let ring = world
+            .spawn((
+                BulletMarker,
+                Lifetime::default(),
+                RotatingBullet {
+                    origin: Vec2::new(100.0, 0.0),
+                    rotation_speed: 2.0,
+                    track_emitter: false,
+                    anchor: None,
+                },
+                Transform::from_xyz(200.0, 0.0, 0.0),
+            ))
+            .id();
+        // starts off flying sideways, so it keeps turning the whole second
+        let homing = world
+            .spawn((
+                BulletMarker,
+                Lifetime::default(),
+                Sprite::default(),
+                Velocity::default(),
+                HomingBullet {
+                    rotation_speed: 1.0,
+                    seeking_time: 10.0,
+                    target: Target::Enemy,
+                },
+                NormalBullet {
+                    velocity: Vec2::new(200.0, 0.0),
+                },
+                Transform::default(),
+            ))
+            .id();
+
+        for _ in 0..hz {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f64(1.0 / hz as f64));
+            app.world_mut().run_schedule(Update);
+        }
+
+        [player, straight, ring, homing]
+            .into_iter()
+            .map(|ent| app.world().get::<Transform>(ent).unwrap().translation.xy())
+            .collect()
+    }
+
+    #[test]
+    fn tick_rate_doesnt_change_trajectories() {
+        let slow = simulate(64);
+        let fast = simulate(128);
+
+        // homing bullets turn in steps, so they only agree up to a unit or two
+        for (a, b) in slow.into_iter().zip(fast) {
+            assert!(a.distance(b) < 2.0, "{a} at 64 Hz but {b} at 128 Hz");
         }
     }
 }
@@ -0,0 +1,146 @@
+use bullet::{AltFire, Weapon};
+use enemy::BulletSpawner;
+use input::{InputFrame, TouhouInput};
+use pool::BulletPool;
+
+use super::*;
+
+const CHARGE_BAR_COLOR: Color = Color::srgba(1.0, 0.7, 0.2, 0.9);
+
+pub fn charge_plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(GameState::Touhou),
+        spawn_charge_bar.in_set(TouhouSets::EnterTouhou),
+    )
+    .add_systems(
+        FixedUpdate,
+        (charge_weapons, update_charge_bar)
+            .chain()
+            .in_set(TouhouSets::Gameplay),
+    );
+}
+
+/// Put next to a [`Weapon`] that doesn't fire on its own. Holding fire while
+/// its group is active builds up charge, letting go fires a single shot that
+/// hits harder, is bigger and costs more ammo the longer it was held. Shots
+/// are still no closer together than the weapon's interval.
+#[derive(Component, Clone, Debug)]
+pub struct Charge {
+    /// seconds to a full charge
+    pub full_time: f32,
+    /// what damage, size and ammo cost get multiplied by at full charge
+    pub max_scale: f32,
+    held: f32,
+}
+
+impl Charge {
+    pub fn new(full_time: f32, max_scale: f32) -> Self {
+        Self {
+            full_time,
+            max_scale,
+            held: 0.0,
+        }
+    }
+
+    fn fraction(&self) -> f32 {
+        (self.held / self.full_time).clamp(0.0, 1.0)
+    }
+
+    fn scale(&self) -> f32 {
+        1.0 + (self.max_scale - 1.0) * self.fraction()
+    }
+}
+
+#[derive(Component)]
+struct ChargeBar;
+
+fn spawn_charge_bar(mut commands: Commands) {
+    commands.spawn((
+        TouhouMarker,
+        ChargeBar,
+        Node {
+            width: Val::Vw(0.0),
+            height: Val::Vh(2.0),
+            left: Val::Px(0.),
+            bottom: -Val::Vh(23.),
+            ..default()
+        },
+        BackgroundColor(CHARGE_BAR_COLOR),
+        ZIndex(1),
+    ));
+}
+
+fn charge_weapons(
+    time: Res<Time>,
+    input: Res<TouhouInput>,
+    mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    mut weapons: Query<(&mut Weapon, &mut Charge, Has<AltFire>)>,
+    player: Option<PlayerQ<(&Transform, &mut Ammo, Has<AltFire>)>>,
+) {
+    let Some((trans, mut ammo, focused)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+    let holding = input.pressed(InputFrame::FIRE);
+
+    for (mut weapon, mut charge, alt) in &mut weapons {
+        weapon.timer.tick(time.delta());
+
+        if holding && alt == focused {
+            charge.held = (charge.held + time.delta_secs()).min(charge.full_time);
+            continue;
+        }
+
+        // let go of fire, or switched groups with something built up
+        if charge.held <= 0.0 {
+            continue;
+        }
+
+        // the charge waits for the gun to be ready again, so tapping fire
+        // can't go faster than the fire rate
+        if !weapon.timer.finished() {
+            continue;
+        }
+        weapon.timer.reset();
+
+        let scale = charge.scale();
+        charge.held = 0.0;
+
+        let cost = (weapon.ammo_cost as f32 * scale).ceil() as u32;
+        if **ammo < cost {
+            continue;
+        }
+        **ammo -= cost;
+
+        let mut bullet = weapon.bullet.clone();
+        scale_bullet(&mut bullet, scale);
+
+        let mut shot = Weapon {
+            timer: weapon.timer.clone(),
+            ammo_cost: cost,
+            bullet,
+            salted: weapon.salted,
+            phasing: weapon.phasing,
+            damage: (weapon.damage as f32 * scale).round() as u32,
+        };
+        shot.spawn_bullet(&mut commands, &mut pool, trans.translation.xy());
+    }
+}
+
+/// Grows the bullet and its hitbox, along with any stage that comes after it.
+fn scale_bullet(spawner: &mut BulletSpawner, scale: f32) {
+    spawner.bullet.collider = spawner.bullet.collider.scaled(scale);
+    spawner.bullet.transform.scale *= scale;
+
+    if let Some(delayed) = &mut spawner.delayed {
+        scale_bullet(&mut delayed.bullet, scale);
+    }
+}
+
+fn update_charge_bar(weapons: Query<&Charge>, mut bar: Query<&mut Node, With<ChargeBar>>) {
+    let fraction = weapons.iter().map(Charge::fraction).fold(0.0, f32::max);
+
+    for mut node in &mut bar {
+        node.width = Val::Vw(20.0 * fraction);
+    }
+}
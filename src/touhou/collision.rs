@@ -0,0 +1,423 @@
+use std::f32::consts::FRAC_PI_2;
+
+use super::*;
+
+/// What an entity collides as, relative to its [`Transform`]. Rects and
+/// capsules turn with the entity's rotation.
+#[derive(Component, Copy, Clone, Debug)]
+pub enum Collider {
+    Circle {
+        radius: f32,
+    },
+    Rect {
+        half_size: Vec2,
+    },
+    /// A segment between two points relative to the entity, with thickness.
+    Capsule {
+        start: Vec2,
+        end: Vec2,
+        radius: f32,
+    },
+}
+
+impl Default for Collider {
+    fn default() -> Self {
+        Collider::Circle { radius: 0.0 }
+    }
+}
+
+impl Collider {
+    pub fn new(radius: f32) -> Self {
+        Collider::Circle { radius }
+    }
+
+    pub fn rect(size: Vec2) -> Self {
+        Collider::Rect {
+            half_size: size / 2.0,
+        }
+    }
+
+    pub fn capsule(start: Vec2, end: Vec2, radius: f32) -> Self {
+        Collider::Capsule { start, end, radius }
+    }
+
+    /// The same shape grown by `by` on every side.
+    pub fn inflated(self, by: f32) -> Self {
+        match self {
+            Collider::Circle { radius } => Collider::Circle {
+                radius: radius + by,
+            },
+            Collider::Rect { half_size } => Collider::Rect {
+                half_size: half_size + by,
+            },
+            Collider::Capsule { start, end, radius } => Collider::Capsule {
+                start,
+                end,
+                radius: radius + by,
+            },
+        }
+    }
+
+    /// The same shape with every length multiplied by `by`.
+    pub fn scaled(self, by: f32) -> Self {
+        match self {
+            Collider::Circle { radius } => Collider::Circle {
+                radius: radius * by,
+            },
+            Collider::Rect { half_size } => Collider::Rect {
+                half_size: half_size * by,
+            },
+            Collider::Capsule { start, end, radius } => Collider::Capsule {
+                start: start * by,
+                end: end * by,
+                radius: radius * by,
+            },
+        }
+    }
+
+    /// How far from the entity's position the shape reaches at most.
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            Collider::Circle { radius } => radius,
+            Collider::Rect { half_size } => half_size.length(),
+            Collider::Capsule { start, end, radius } => start.length().max(end.length()) + radius,
+        }
+    }
+
+    /// Places the collider in the world.
+    pub fn shape(&self, t: &Transform) -> Shape {
+        let pos = t.translation.xy();
+        let dir = (t.rotation * Vec3::X).xy().normalize_or(Vec2::X);
+
+        match *self {
+            Collider::Circle { radius } => Shape::Circle(Circle::new(radius, pos)),
+            Collider::Rect { half_size } => Shape::Rect(Obb {
+                center: pos,
+                half_size,
+                dir,
+            }),
+            Collider::Capsule { start, end, radius } => Shape::Capsule(Capsule::new(
+                pos + dir.rotate(start),
+                pos + dir.rotate(end),
+                radius,
+            )),
+        }
+    }
+}
+
+/// A [`Collider`] placed in the world, ready to be tested against another.
+#[derive(Copy, Clone, Debug)]
+pub enum Shape {
+    Circle(Circle),
+    Rect(Obb),
+    Capsule(Capsule),
+}
+
+impl Shape {
+    pub fn hits(&self, other: &Shape) -> bool {
+        match (self, other) {
+            (Shape::Circle(a), Shape::Circle(b)) => a.hits(*b),
+            (Shape::Circle(c), Shape::Capsule(k)) | (Shape::Capsule(k), Shape::Circle(c)) => {
+                k.hits(*c)
+            }
+            (Shape::Circle(c), Shape::Rect(r)) | (Shape::Rect(r), Shape::Circle(c)) => {
+                r.distance(c.pos) < c.radius
+            }
+            (Shape::Capsule(a), Shape::Capsule(b)) => {
+                segment_distance(a.a, a.b, b.a, b.b) < a.radius + b.radius
+            }
+            (Shape::Capsule(k), Shape::Rect(r)) | (Shape::Rect(r), Shape::Capsule(k)) => {
+                r.segment_distance(k.a, k.b) < k.radius
+            }
+            (Shape::Rect(a), Shape::Rect(b)) => a.hits(b),
+        }
+    }
+
+    pub fn draw(&self, gizmos: &mut Gizmos, color: impl Into<Color>) {
+        match *self {
+            Shape::Circle(c) => {
+                gizmos.circle_2d(Isometry2d::from_translation(c.pos), c.radius, color);
+            }
+            Shape::Rect(r) => {
+                gizmos.rect_2d(
+                    Isometry2d::new(r.center, Rot2::from_sin_cos(r.dir.y, r.dir.x)),
+                    r.half_size * 2.0,
+                    color,
+                );
+            }
+            Shape::Capsule(k) => {
+                let axis = k.b - k.a;
+
+                // bevy's capsule stands upright, so turn it onto the segment
+                gizmos.primitive_2d(
+                    &Capsule2d::new(k.radius, axis.length()),
+                    Isometry2d::new(
+                        (k.a + k.b) / 2.0,
+                        Rot2::radians(axis.to_angle() - FRAC_PI_2),
+                    ),
+                    color,
+                );
+            }
+        }
+    }
+}
+
+/// A rectangle turned so its x axis points along `dir`.
+#[derive(Copy, Clone, Debug)]
+pub struct Obb {
+    center: Vec2,
+    half_size: Vec2,
+    dir: Vec2,
+}
+
+impl Obb {
+    // point in the rect's own frame, where it's an axis-aligned box
+    fn to_local(self, point: Vec2) -> Vec2 {
+        let d = point - self.center;
+        Vec2::new(d.dot(self.dir), d.dot(self.dir.perp()))
+    }
+
+    fn corners(&self) -> [Vec2; 4] {
+        let x = self.dir * self.half_size.x;
+        let y = self.dir.perp() * self.half_size.y;
+
+        [
+            self.center + x + y,
+            self.center - x + y,
+            self.center - x - y,
+            self.center + x - y,
+        ]
+    }
+
+    /// Zero for points inside the rect.
+    fn distance(&self, point: Vec2) -> f32 {
+        let local = self.to_local(point);
+        (local - local.clamp(-self.half_size, self.half_size)).length()
+    }
+
+    fn segment_distance(&self, a: Vec2, b: Vec2) -> f32 {
+        if self.crosses(a, b) {
+            return 0.0;
+        }
+
+        // if they don't touch, the closest pair always has an endpoint or a
+        // corner in it
+        self.corners()
+            .into_iter()
+            .map(|corner| point_segment_distance(corner, a, b))
+            .chain([self.distance(a), self.distance(b)])
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    // clips the segment against the box one axis at a time
+    fn crosses(&self, a: Vec2, b: Vec2) -> bool {
+        let (a, b) = (self.to_local(a), self.to_local(b));
+        let d = b - a;
+        let (mut t_min, mut t_max) = (0.0_f32, 1.0_f32);
+
+        for axis in 0..2 {
+            let (start, delta, half) = (a[axis], d[axis], self.half_size[axis]);
+
+            if delta == 0.0 {
+                if start.abs() > half {
+                    return false;
+                }
+                continue;
+            }
+
+            let t1 = (-half - start) / delta;
+            let t2 = (half - start) / delta;
+
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+
+            if t_min > t_max {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    // separating axis test, a rect only has two axes worth checking
+    fn hits(&self, other: &Obb) -> bool {
+        let offset = other.center - self.center;
+
+        let extent = |rect: &Obb, axis: Vec2| {
+            rect.half_size.x * rect.dir.dot(axis).abs()
+                + rect.half_size.y * rect.dir.perp().dot(axis).abs()
+        };
+
+        [self.dir, self.dir.perp(), other.dir, other.dir.perp()]
+            .into_iter()
+            .all(|axis| offset.dot(axis).abs() < extent(self, axis) + extent(other, axis))
+    }
+}
+
+fn closest_on_segment(point: Vec2, a: Vec2, b: Vec2) -> Vec2 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+
+    if len_sq == 0.0 {
+        return a;
+    }
+
+    let t = ((point - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    a + ab * t
+}
+
+fn point_segment_distance(point: Vec2, a: Vec2, b: Vec2) -> f32 {
+    point.distance(closest_on_segment(point, a, b))
+}
+
+fn segment_distance(a1: Vec2, b1: Vec2, a2: Vec2, b2: Vec2) -> f32 {
+    let (d1, d2) = (b1 - a1, b2 - a2);
+    let denom = d1.perp_dot(d2);
+
+    if denom != 0.0 {
+        let t = (a2 - a1).perp_dot(d2) / denom;
+        let u = (a2 - a1).perp_dot(d1) / denom;
+
+        if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+            return 0.0;
+        }
+    }
+
+    [
+        point_segment_distance(a1, a2, b2),
+        point_segment_distance(b1, a2, b2),
+        point_segment_distance(a2, a1, b1),
+        point_segment_distance(b2, a1, b1),
+    ]
+    .into_iter()
+    .fold(f32::INFINITY, f32::min)
+}
+
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Circle {
+    pub pos: Vec2,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(radius: f32, pos: Vec2) -> Self {
+        Self { pos, radius }
+    }
+
+    #[allow(dead_code)]
+    pub fn within(&self, rect: Rect) -> bool {
+        let Self { pos, radius } = *self;
+
+        let bounding_rect = Rect::from_center_half_size(pos, Vec2::splat(radius));
+
+        rect.contains(bounding_rect.min) && rect.contains(bounding_rect.max)
+    }
+
+    pub fn hits(&self, other: Circle) -> bool {
+        (self.pos - other.pos).length() - (self.radius + other.radius) < 0.0
+    }
+}
+
+/// A line segment from `a` to `b` with some thickness, what a laser covers.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Capsule {
+    pub a: Vec2,
+    pub b: Vec2,
+    pub radius: f32,
+}
+
+impl Capsule {
+    pub fn new(a: Vec2, b: Vec2, radius: f32) -> Self {
+        Self { a, b, radius }
+    }
+
+    pub fn hits(&self, other: Circle) -> bool {
+        point_segment_distance(other.pos, self.a, self.b) < self.radius + other.radius
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    fn place(collider: Collider, x: f32, y: f32, angle: f32) -> Shape {
+        collider.shape(&Transform::from_xyz(x, y, 0.0).with_rotation(Quat::from_rotation_z(angle)))
+    }
+
+    fn wide_rect() -> Shape {
+        place(Collider::rect(Vec2::new(100.0, 40.0)), 0.0, 0.0, 0.0)
+    }
+
+    #[test]
+    fn circle_rect() {
+        let circle = |x, y| place(Collider::new(10.0), x, y, 0.0);
+
+        assert!(circle(55.0, 0.0).hits(&wide_rect()));
+        assert!(!circle(65.0, 0.0).hits(&wide_rect()));
+        // inside the rect grown by the radius, but still clear of the corner
+        assert!(!wide_rect().hits(&circle(58.0, 28.0)));
+    }
+
+    #[test]
+    fn rotated_rects_split_by_one_axis() {
+        let diamond = |x, y| place(Collider::rect(Vec2::splat(20.0)), x, y, FRAC_PI_4);
+
+        // overlaps on both of the wide rect's axes and one of the diamond's,
+        // only the diamond's other axis separates them
+        assert!(!wide_rect().hits(&diamond(60.0, 30.0)));
+        assert!(!diamond(60.0, 30.0).hits(&wide_rect()));
+
+        assert!(wide_rect().hits(&diamond(55.0, 27.0)));
+    }
+
+    #[test]
+    fn capsule_rect() {
+        let beam = |start: Vec2, end: Vec2, radius, angle| {
+            place(Collider::capsule(start, end, radius), 0.0, 0.0, angle)
+        };
+
+        // straight through, with both ends outside the rect
+        assert!(beam(Vec2::new(-100.0, 0.0), Vec2::new(100.0, 0.0), 1.0, 0.0).hits(&wide_rect()));
+
+        // running alongside the top edge, 10 units above it
+        let above = |radius| beam(Vec2::new(-100.0, 30.0), Vec2::new(100.0, 30.0), radius, 0.0);
+        assert!(!above(5.0).hits(&wide_rect()));
+        assert!(wide_rect().hits(&above(15.0)));
+
+        // the capsule turns with the entity, pointing down at the rect
+        let down = |length: f32| {
+            beam(
+                Vec2::new(200.0, 0.0),
+                Vec2::new(200.0 - length, 0.0),
+                2.0,
+                FRAC_PI_2,
+            )
+        };
+        assert!(!down(100.0).hits(&wide_rect()));
+        assert!(down(185.0).hits(&wide_rect()));
+    }
+
+    #[test]
+    fn parallel_and_collinear_segments() {
+        let (a, b) = (Vec2::ZERO, Vec2::new(10.0, 0.0));
+
+        assert_eq!(
+            segment_distance(a, b, Vec2::new(0.0, 5.0), Vec2::new(10.0, 5.0)),
+            5.0
+        );
+        assert_eq!(
+            segment_distance(a, b, Vec2::new(5.0, 0.0), Vec2::new(15.0, 0.0)),
+            0.0
+        );
+        assert_eq!(
+            segment_distance(a, b, Vec2::new(12.0, 0.0), Vec2::new(20.0, 0.0)),
+            2.0
+        );
+        assert_eq!(
+            segment_distance(a, b, Vec2::new(5.0, -1.0), Vec2::new(5.0, 1.0)),
+            0.0
+        );
+    }
+}
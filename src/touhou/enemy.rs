@@ -1,43 +1,67 @@
 use rand::prelude::*;
 use std::{f32::consts::TAU, time::Duration};
 
-use bevy::{color, time::Stopwatch};
+use bevy::{
+    color,
+    math::curve::{Curve, EaseFunction, EasingCurve},
+    sprite::Anchor,
+    time::Stopwatch,
+};
 use bullet::{
-    BulletBundle, BulletCommandExt, HomingBullet, NormalBullet, RotatingBullet, StutterBullet,
-    Target, WaveBullet,
+    BulletBundle, BulletCommandExt, HomingBullet, LaserBullet, NormalBullet, RotatingBullet,
+    StutterBullet, Target, WaveBullet,
 };
 
 use super::{
-    bullet::{DelayedBullet, Velocity},
+    bomb::BombUsed,
+    bullet::{DelayedBullet, LifeLost, Lifetime, Velocity},
+    items::ItemDrops,
+    pool::BulletPool,
+    replay::{RngStream, TouhouRng},
     *,
 };
 
 pub fn enemy_plugin(app: &mut App) {
-    app.add_systems(OnEnter(GameState::Touhou), spawn_enemy)
-        .insert_resource(EncounterTime {
-            time: Stopwatch::new(),
-        })
-        .add_systems(Update, animate_sprites)
-        .add_systems(
-            OnEnter(GameState::Touhou),
-            |mut time: ResMut<EncounterTime>| time.time.reset(),
+    app.insert_resource(EncounterTime {
+        time: Stopwatch::new(),
+    })
+    .add_systems(Update, animate_sprites)
+    .add_systems(
+        OnEnter(GameState::Touhou),
+        |mut time: ResMut<EncounterTime>| time.time.reset(),
+    )
+    .add_systems(
+        FixedUpdate,
+        (
+            circular_rotating_emitter,
+            circular_homing_emitter,
+            circular_wave_emitter,
+            spray_emitter,
+            tentacle_emitter,
+            rotating_spray_emitter,
+            divisive_emitter,
+            flood_emitter,
+            laser_emitter,
+            skip_to_practice_card.before(advance_encounter_time),
+            advance_encounter_time,
+            process_spellcards,
+            move_boss.after(process_spellcards),
+            score_spellcards.after(advance_encounter_time),
+            track_max_health,
         )
-        .add_systems(
-            FixedUpdate,
-            (
-                circular_rotating_emitter,
-                circular_homing_emitter,
-                circular_wave_emitter,
-                spray_emitter,
-                tentacle_emitter,
-                rotating_spray_emitter,
-                divisive_emitter,
-                flood_emitter,
-                advance_encounter_time,
-                process_spellcards,
-            )
-                .in_set(TouhouSets::Gameplay),
-        );
+            .in_set(TouhouSets::Gameplay),
+    )
+    .add_systems(
+        FixedPreUpdate,
+        (place_new_emitters, apply_difficulty, scale_volleys).in_set(TouhouSets::Gameplay),
+    )
+    .add_systems(
+        FixedPostUpdate,
+        settle_boss_kill
+            .after(bullet::process_enemy_hits)
+            .before(super::enemy_dead)
+            .in_set(TouhouSets::Gameplay),
+    );
 }
 
 #[derive(Resource)]
@@ -52,6 +76,7 @@ pub struct EnemyBundle {
     transform: Transform,
     collider: Collider,
     health: Health,
+    drops: ItemDrops,
     markers: (EnemyMarker, TouhouMarker, HasEmitters),
 }
 
@@ -61,6 +86,48 @@ pub struct EnemyMarker;
 #[derive(Component, Deref, DerefMut, Default)]
 pub struct Health(u32);
 
+/// The health an enemy started with, for health bars.
+#[derive(Component, Deref)]
+pub struct MaxHealth(u32);
+
+/// The enemy the stage ends with, as opposed to the mooks before it.
+#[derive(Component, Default)]
+#[require(BossMover)]
+pub struct Boss;
+
+// where bosses come in from, their first card moves them on screen
+const BOSS_ENTRY: Vec2 = Vec2::new(1100.0, 0.0);
+
+/// How the boss moves while a spellcard is running. Its emitters are children
+/// of it, so they come along.
+#[derive(Clone, Copy, Debug)]
+pub enum BossMovement {
+    /// Eases from wherever the boss was when the card started to `to`.
+    MoveTo { to: Vec2, duration: f32 },
+    /// Eases to a random point in `area` every `interval` seconds.
+    Wander { area: Rect, interval: f32 },
+    /// Stays at `x` and keeps level with the player, at `speed` units/s.
+    FollowPlayer { x: f32, speed: f32 },
+    /// Loops a figure eight `size` wide and tall around `center`.
+    FigureEight {
+        center: Vec2,
+        size: Vec2,
+        period: f32,
+    },
+}
+
+// how long the boss takes to get onto a figure eight from wherever it was
+const FIGURE_EIGHT_BLEND: f32 = 1.0;
+
+/// Where the boss is in its current [`BossMovement`].
+#[derive(Component, Default)]
+struct BossMover {
+    card: Option<Entity>,
+    from: Vec2,
+    target: Vec2,
+    elapsed: f32,
+}
+
 #[derive(Component, Default)]
 struct HasEmitters;
 
@@ -115,6 +182,15 @@ pub struct RotatingSprayEmitter {
     spray_count: usize,
 }
 
+/// Fires `count` lasers at once, `spread` radians apart, centred on the player
+/// if `aimed` and on the emitter's right otherwise.
+#[derive(Component, Default)]
+pub struct LaserEmitter {
+    count: usize,
+    spread: f32,
+    aimed: bool,
+}
+
 #[derive(Component, Default)]
 pub struct DivisiveEmitter {
     columns: u64,
@@ -163,10 +239,113 @@ struct Emitter {
 struct Active(bool);
 
 #[derive(Component)]
+#[require(SpellcardCapture)]
 struct Spellcard {
     emitters: Vec<Entity>,
     start_time: f32,
     end_time: f32,
+    // cards without one leave the boss to whatever else is running
+    movement: Option<BossMovement>,
+    /// where the card is in [`spellcards`], background layers that only run
+    /// alongside the real cards don't have one
+    index: Option<usize>,
+}
+
+impl Spellcard {
+    fn new(
+        cards: &[SpellcardInfo],
+        index: usize,
+        emitters: Vec<Entity>,
+        movement: Option<BossMovement>,
+    ) -> Self {
+        Self {
+            emitters,
+            start_time: cards[index].start_time,
+            end_time: cards[index].end_time,
+            movement,
+            index: Some(index),
+        }
+    }
+}
+
+/// A boss's spellcard as the practice menu sees it.
+#[derive(Clone, Copy, Debug)]
+pub struct SpellcardInfo {
+    pub name: &'static str,
+    /// seconds into the boss fight
+    pub start_time: f32,
+    pub end_time: f32,
+}
+
+const fn card(name: &'static str, start_time: f32, end_time: f32) -> SpellcardInfo {
+    SpellcardInfo {
+        name,
+        start_time,
+        end_time,
+    }
+}
+
+const RED_GIRL_CARDS: &[SpellcardInfo] = &[
+    card("Fire Sign \"Homing Embers\"", 0.0, 25.0),
+    card("Fire Sign \"Twin Spirals\"", 25.0, 45.0),
+    card("Fire Sign \"Heat Shimmer Lance\"", 45.0, 70.0),
+];
+
+const TENTACLE_CARDS: &[SpellcardInfo] = &[
+    card("Slime \"Spitting Frenzy\"", 0.0, 25.0),
+    card("Slime \"Grasping Tentacles\"", 25.0, 45.0),
+    card("Slime \"Last Gasp Spray\"", 45.0, 70.0),
+];
+
+const LIZARD_CARDS: &[SpellcardInfo] = &[
+    card("Scale \"Venom Spray\"", 0.0, 25.0),
+    card("Scale \"Whirling Spit\"", 25.0, 45.0),
+    card("Scale \"Orbiting Fangs\"", 45.0, 70.0),
+];
+
+const MOON_GIRL_CARDS: &[SpellcardInfo] = &[
+    card("Moon Sign \"Lunar Lattice\"", 0.0, 25.0),
+    card("Moon Sign \"Tidal Flood\"", 25.0, 45.0),
+    card("Moon Sign \"Crossed Moonbeams\"", 45.0, 70.0),
+    card("Moon Sign \"Eclipse Rings\"", 70.0, 100.0),
+];
+
+/// Every spellcard a boss has, in the order they come up.
+pub fn spellcards(enemy: Enemies) -> &'static [SpellcardInfo] {
+    match enemy {
+        Enemies::RedGirl => RED_GIRL_CARDS,
+        Enemies::Tentacle => TENTACLE_CARDS,
+        Enemies::Lizard => LIZARD_CARDS,
+        Enemies::MoonGirl => MOON_GIRL_CARDS,
+    }
+}
+
+/// Tracks whether the player got through the current run of a spellcard
+/// without losing a life or bombing.
+#[derive(Component, Default)]
+struct SpellcardCapture {
+    active: bool,
+    failed: bool,
+}
+
+// practice runs start the fight at the chosen card as soon as the boss is in
+fn skip_to_practice_card(
+    params: Res<MissionParams>,
+    mut enc_time: ResMut<EncounterTime>,
+    bosses: Query<(), Added<Boss>>,
+) {
+    let Some(info) = params
+        .practice
+        .and_then(|card| spellcards(params.enemy).get(card))
+    else {
+        return;
+    };
+
+    if !bosses.is_empty() {
+        enc_time
+            .time
+            .set_elapsed(Duration::from_secs_f32(info.start_time));
+    }
 }
 
 fn advance_encounter_time(
@@ -186,8 +365,13 @@ fn process_spellcards(
     cards: Query<&Spellcard>,
     mut emitters: Query<&mut Active, With<Emitter>>,
 ) {
-    for mut active in &mut emitters {
-        **active = false;
+    // emitters outside of any card, like the mooks', are always on
+    for card in &cards {
+        for ent in &card.emitters {
+            if let Ok(mut active) = emitters.get_mut(*ent) {
+                **active = false;
+            }
+        }
     }
     let current_time = enc_time.time.elapsed_secs();
 
@@ -203,6 +387,161 @@ fn process_spellcards(
     }
 }
 
+type PlayerNotBoss = (With<PlayerMarker>, Without<Boss>);
+
+fn move_boss(
+    time: Res<Time>,
+    enc_time: Res<EncounterTime>,
+    area: Res<GameplayRect>,
+    mut rng: ResMut<TouhouRng>,
+    cards: Query<(Entity, &Spellcard)>,
+    player: Option<Single<&Transform, PlayerNotBoss>>,
+    mut bosses: Query<(&mut Transform, &mut BossMover), With<Boss>>,
+) {
+    let current_time = enc_time.time.elapsed_secs();
+    let Some((card_ent, movement)) = cards.iter().find_map(|(ent, card)| {
+        let active = card.start_time < current_time && current_time < card.end_time;
+        card.movement.filter(|_| active).map(|m| (ent, m))
+    }) else {
+        return;
+    };
+
+    let player_y = player.map(|p| p.translation.y);
+    let rng = rng.stream(RngStream::Boss);
+
+    for (mut trans, mut mover) in &mut bosses {
+        let pos = trans.translation.xy();
+
+        if mover.card != Some(card_ent) {
+            *mover = BossMover {
+                card: Some(card_ent),
+                from: pos,
+                target: pos,
+                elapsed: 0.0,
+            };
+        }
+        mover.elapsed += time.delta_secs();
+
+        let ease = |from, to, t: f32| {
+            EasingCurve::new(from, to, EaseFunction::CubicInOut).sample_clamped(t)
+        };
+
+        let new_pos = match movement {
+            BossMovement::MoveTo { to, duration } => ease(mover.from, to, mover.elapsed / duration),
+            BossMovement::Wander {
+                area: wander,
+                interval,
+            } => {
+                if mover.elapsed >= interval {
+                    let wander = wander.intersect(area.rect);
+
+                    mover.elapsed -= interval;
+                    mover.from = pos;
+                    mover.target = Vec2::new(
+                        rng.random_range(wander.min.x..=wander.max.x),
+                        rng.random_range(wander.min.y..=wander.max.y),
+                    );
+                }
+                ease(mover.from, mover.target, mover.elapsed / interval)
+            }
+            BossMovement::FollowPlayer { x, speed } => {
+                let target = Vec2::new(x, player_y.unwrap_or(pos.y));
+                pos.move_towards(target, speed * time.delta_secs())
+            }
+            BossMovement::FigureEight {
+                center,
+                size,
+                period,
+            } => {
+                let t = mover.elapsed / period * TAU;
+                let on_path = center + size / 2.0 * Vec2::new(t.sin(), (2.0 * t).sin() / 2.0);
+                mover
+                    .from
+                    .lerp(on_path, (mover.elapsed / FIGURE_EIGHT_BLEND).min(1.0))
+            }
+        };
+
+        trans.translation = new_pos.extend(trans.translation.z);
+    }
+}
+
+fn score_spellcards(
+    enc_time: Res<EncounterTime>,
+    params: Res<MissionParams>,
+    mut lost: EventReader<LifeLost>,
+    mut bombs: EventReader<BombUsed>,
+    mut result: ResMut<MissionResult>,
+    mut mission_state: ResMut<NextState<MissionState>>,
+    mut cards: Query<(&Spellcard, &mut SpellcardCapture)>,
+) {
+    // bombing your way through a card doesn't count as capturing it either
+    let bombed = bombs.read().count() > 0;
+    let life_lost = lost.read().count() > 0 || bombed;
+    let current_time = enc_time.time.elapsed_secs();
+
+    for (card, mut capture) in &mut cards {
+        let active = card.start_time < current_time && current_time < card.end_time;
+
+        if active && life_lost {
+            capture.failed = true;
+        }
+
+        // the card just ran out, pay out if nothing got through
+        if capture.active && !active {
+            settle_card(card, &mut capture, &params, &mut result, &mut mission_state);
+        }
+
+        capture.active = active;
+    }
+}
+
+// taking the boss down ends the card it was on just as well as outlasting it
+// does. This has to happen before enemy_dead, after that there's no telling
+// the boss went down from there being no boss.
+fn settle_boss_kill(
+    mut commands: Commands,
+    params: Res<MissionParams>,
+    mut result: ResMut<MissionResult>,
+    mut mission_state: ResMut<NextState<MissionState>>,
+    bosses: Query<&Health, (With<Boss>, With<EnemyMarker>)>,
+    mut cards: Query<(Entity, &Spellcard, &mut SpellcardCapture)>,
+) {
+    if !bosses.iter().any(|health| **health == 0) {
+        return;
+    }
+
+    for (ent, card, mut capture) in &mut cards {
+        if capture.active {
+            settle_card(card, &mut capture, &params, &mut result, &mut mission_state);
+        }
+
+        // the fight's over, nothing left to score
+        commands.entity(ent).remove::<SpellcardCapture>();
+    }
+}
+
+/// Pays out for a card that's over if nothing got through, and ends the run
+/// if it was the card being practiced.
+fn settle_card(
+    card: &Spellcard,
+    capture: &mut SpellcardCapture,
+    params: &MissionParams,
+    result: &mut MissionResult,
+    mission_state: &mut NextState<MissionState>,
+) {
+    if !capture.failed {
+        result.spellcards_captured += 1;
+        result.score += score::SPELLCARD_POINTS;
+    }
+
+    // a practice run is over once its card is
+    if card.index.is_some() && card.index == params.practice {
+        result.practice_captured = !capture.failed;
+        mission_state.set(MissionState::Success);
+    }
+    capture.failed = false;
+}
+
 #[derive(Bundle)]
 pub struct EmitterBundle {
     emitter: Emitter,
@@ -220,6 +559,7 @@ pub struct BulletSpawner {
     pub homing: Option<HomingBullet>,
     pub wave: Option<WaveBullet>,
     pub delayed: Option<Box<DelayedBullet>>,
+    pub laser: Option<LaserBullet>,
 }
 
 impl BulletSpawner {
@@ -242,6 +582,9 @@ impl BulletSpawner {
         if let Some(delayed) = self.delayed {
             commands.add_bullet(*delayed.clone());
         }
+        if let Some(laser) = self.laser {
+            commands.add_bullet(laser);
+        }
     }
 
     pub fn new(bullet: BulletBundle) -> Self {
@@ -263,11 +606,22 @@ impl BulletSpawner {
             rotation: Some(RotatingBullet {
                 origin,
                 rotation_speed,
+                track_emitter: false,
+                anchor: None,
             }),
             ..self
         }
     }
 
+    /// Makes rotating bullets keep circling their emitter as it moves, instead
+    /// of the spot they were fired from.
+    pub fn track_emitter(mut self) -> Self {
+        if let Some(rotation) = &mut self.rotation {
+            rotation.track_emitter = true;
+        }
+        self
+    }
+
     pub fn homing(self, seeking_time: f32, rotation_speed: f32, target: bullet::Target) -> Self {
         Self {
             homing: Some(HomingBullet {
@@ -306,13 +660,47 @@ impl BulletSpawner {
             ..self
         }
     }
+
+    /// A laser that warns for `warning` seconds and then stays up for
+    /// `duration`, after which it's culled.
+    pub fn laser(
+        self,
+        length: f32,
+        width: f32,
+        warning: f32,
+        duration: f32,
+        rotation_speed: f32,
+    ) -> Self {
+        let laser = LaserBullet::new(length, width, warning, rotation_speed);
+
+        Self {
+            bullet: BulletBundle {
+                collider: laser.collider(),
+                ..self.bullet
+            },
+            laser: Some(laser),
+            ..self
+        }
+        .max_lifetime(warning + duration)
+    }
+
+    pub fn max_lifetime(self, max: f32) -> Self {
+        Self {
+            bullet: BulletBundle {
+                lifetime: Lifetime::with_max(max),
+                ..self.bullet
+            },
+            ..self
+        }
+    }
 }
 
 fn divisive_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut DivisiveEmitter,
@@ -339,7 +727,7 @@ fn divisive_emitter(
                 bullet.transform.translation +=
                     Vec2::from((-960.0 + (gap * (i + 1) as f32), 600.0)).extend(0.0);
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = Vec2::from((0.0, -1.0)).rotate(normal.velocity);
@@ -352,7 +740,7 @@ fn divisive_emitter(
                 bullet.transform.translation +=
                     Vec2::from((1000.0, -540.0 + (gap * (i + 1) as f32))).extend(0.0);
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = Vec2::from((-1.0, 0.0)).rotate(normal.velocity);
@@ -365,9 +753,11 @@ fn divisive_emitter(
 
 fn circular_rotating_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        Entity,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut CircularAimedEmitter,
@@ -376,7 +766,7 @@ fn circular_rotating_emitter(
     player: Single<&Transform, With<PlayerMarker>>,
 ) {
     let playerpos = player.into_inner();
-    for (trans, mut emitter, spawner, circ, active) in &mut query {
+    for (ent, trans, mut emitter, spawner, circ, active) in &mut query {
         if !**active {
             continue;
         }
@@ -385,7 +775,7 @@ fn circular_rotating_emitter(
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
 
         if emitter.timer.finished() {
             emitter.timer.reset();
@@ -397,14 +787,17 @@ fn circular_rotating_emitter(
                 bullet.transform.translation += (dir * circ.offset).extend(0.0);
                 let player_dir = playerpos.translation.xy() - bullet.transform.translation.xy();
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = dir.rotate(normal.velocity);
                     commands.add_bullet(NormalBullet { velocity });
                 }
                 if let Some(mut rotating) = spawner.rotation {
-                    rotating.origin += trans.translation.xy();
+                    if rotating.track_emitter {
+                        rotating.anchor = Some((ent, rotating.origin));
+                    }
+                    rotating.origin += trans.translation().xy();
                     commands.add_bullet(rotating);
                 }
                 if let Some(mut rotating) = spawner.stutter {
@@ -420,9 +813,10 @@ fn circular_rotating_emitter(
 
 fn tentacle_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut TentacleEmitter,
@@ -440,7 +834,7 @@ fn tentacle_emitter(
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
 
         if emitter.timer.finished() {
             emitter.timer.reset();
@@ -452,14 +846,14 @@ fn tentacle_emitter(
                 bullet.transform.translation += (dir * circ.offset).extend(0.0);
                 let player_dir = playerpos.translation.xy() - bullet.transform.translation.xy();
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = dir.rotate(normal.velocity);
                     commands.add_bullet(NormalBullet { velocity });
                 }
                 let mut delayed_spawner = BulletSpawner::new(BulletBundle {
-                    collider: Collider { radius: 5.0 },
+                    collider: Collider::new(5.0),
                     sprite: Sprite {
                         image: spawner.bullet.sprite.image.clone(),
                         ..Default::default()
@@ -480,9 +874,11 @@ fn tentacle_emitter(
 
 fn spray_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    mut rng: ResMut<TouhouRng>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut SprayEmitter,
@@ -492,17 +888,17 @@ fn spray_emitter(
     mut gizmos: Gizmos,
 ) {
     let playerpos = player.translation.xy();
+    let rng = rng.stream(RngStream::Spray);
     for (trans, mut emitter, spawner, mut spray, active) in &mut query {
         if !**active {
             continue;
         }
 
-        let mut rng = rand::rng();
         emitter.timer.tick(time.delta());
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
 
         if emitter.timer.elapsed_secs() < spray.firing_time {
             spray.count += (time.delta().as_secs_f32() / spray.firing_speed);
@@ -512,9 +908,9 @@ fn spray_emitter(
                     rng.random_range((spray.spray_width / -2.0)..=(spray.spray_width / 2.0)),
                 );
 
-                let dir = (playerpos - trans.translation.xy()).normalize();
+                let dir = (playerpos - trans.translation().xy()).normalize();
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = ang.rotate(normal.velocity);
@@ -536,9 +932,11 @@ fn spray_emitter(
 
 fn rotating_spray_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    mut rng: ResMut<TouhouRng>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut RotatingSprayEmitter,
@@ -548,17 +946,17 @@ fn rotating_spray_emitter(
     mut gizmos: Gizmos,
 ) {
     let playerpos = player.translation.xy();
+    let rng = rng.stream(RngStream::RotatingSpray);
     for (trans, mut emitter, spawner, mut spray, active) in &mut query {
         if !**active {
             continue;
         }
 
-        let mut rng = rand::rng();
         emitter.timer.tick(time.delta());
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
         spray.rotation += spray.rotation_speed * time.delta_secs();
 
         if emitter.timer.elapsed_secs() < spray.firing_time {
@@ -572,7 +970,7 @@ fn rotating_spray_emitter(
                             + (TAU / spray.spray_count as f32 * i as f32),
                     );
 
-                    let mut commands = commands.spawn(bullet);
+                    let mut commands = pool.spawn(&mut commands, bullet);
 
                     if let Some(normal) = spawner.normal {
                         let velocity = ang.rotate(normal.velocity);
@@ -591,9 +989,11 @@ fn rotating_spray_emitter(
 
 fn flood_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    mut rng: ResMut<TouhouRng>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut FloodEmitter,
@@ -603,12 +1003,12 @@ fn flood_emitter(
     mut gizmos: Gizmos,
 ) {
     let playerpos = player.translation.xy();
+    let rng = rng.stream(RngStream::Flood);
     for (trans, mut emitter, spawner, mut spray, active) in &mut query {
         if !**active {
             continue;
         }
 
-        let mut rng = rand::rng();
         emitter.timer.tick(time.delta());
 
         let mut bullet = spawner.bullet.clone();
@@ -623,7 +1023,7 @@ fn flood_emitter(
 
             bullet.transform.translation = Vec2::from((920.0, placement)).extend(0.0);
 
-            let mut commands = commands.spawn(bullet);
+            let mut commands = pool.spawn(&mut commands, bullet);
 
             if let Some(normal) = spawner.normal {
                 let velocity = ang.rotate(normal.velocity);
@@ -633,13 +1033,62 @@ fn flood_emitter(
     }
 }
 
+fn laser_emitter(
+    mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    time: Res<Time>,
+    mut query: Query<(
+        &GlobalTransform,
+        &mut Emitter,
+        &BulletSpawner,
+        &LaserEmitter,
+        &Active,
+    )>,
+    player: Single<&Transform, With<PlayerMarker>>,
+) {
+    let playerpos = player.translation.xy();
+    for (trans, mut emitter, spawner, lasers, active) in &mut query {
+        if !**active {
+            continue;
+        }
+
+        emitter.timer.tick(time.delta());
+
+        if emitter.timer.finished() {
+            emitter.timer.reset();
+
+            let origin = trans.translation();
+            let base = if lasers.aimed {
+                (playerpos - origin.xy()).to_angle()
+            } else {
+                0.0
+            };
+
+            for i in 0..lasers.count {
+                let mut bullet = spawner.bullet.clone();
+                let angle = base + lasers.spread * (i as f32 - (lasers.count - 1) as f32 / 2.0);
+
+                bullet.transform.translation += origin;
+                bullet.transform.rotation = Quat::from_rotation_z(angle);
+
+                let mut commands = pool.spawn(&mut commands, bullet);
+
+                if let Some(laser) = spawner.laser.clone() {
+                    commands.add_bullet(laser);
+                }
+            }
+        }
+    }
+}
+
 const BULLET_SIZE: f32 = 15.0;
 
 fn circular_wave_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut CircularWaveEmitter,
@@ -657,7 +1106,7 @@ fn circular_wave_emitter(
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
 
         if emitter.timer.finished() {
             emitter.timer.reset();
@@ -669,7 +1118,7 @@ fn circular_wave_emitter(
                 bullet.transform.translation += (dir * circ.offset).extend(0.0);
                 let player_dir = playerpos.translation.xy() - bullet.transform.translation.xy();
 
-                let mut commands = commands.spawn(bullet);
+                let mut commands = pool.spawn(&mut commands, bullet);
 
                 if let Some(normal) = spawner.normal {
                     let velocity = dir.rotate(normal.velocity);
@@ -691,9 +1140,10 @@ fn circular_wave_emitter(
 
 fn circular_homing_emitter(
     mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
     time: Res<Time>,
     mut query: Query<(
-        &Transform,
+        &GlobalTransform,
         &mut Emitter,
         &BulletSpawner,
         &mut CircularHomingEmitter,
@@ -711,7 +1161,7 @@ fn circular_homing_emitter(
 
         let mut bullet = spawner.bullet.clone();
 
-        bullet.transform.translation += trans.translation;
+        bullet.transform.translation += trans.translation();
 
         if emitter.timer.finished() {
             emitter.timer.reset();
@@ -722,7 +1172,7 @@ fn circular_homing_emitter(
             bullet.transform.translation += (dir * circ.offset).extend(0.0);
             let player_dir = playerpos.translation.xy() - bullet.transform.translation.xy();
 
-            let mut commands = commands.spawn(bullet);
+            let mut commands = pool.spawn(&mut commands, bullet);
 
             if let Some(normal) = spawner.normal {
                 let velocity = dir.rotate(normal.velocity);
@@ -740,9 +1190,147 @@ fn circular_homing_emitter(
     }
 }
 
-pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Res<MissionParams>) {
+// emitters fire from their GlobalTransform, which only gets propagated after
+// the fixed update, so work out where freshly spawned ones are by hand
+fn place_new_emitters(
+    mut emitters: Query<(&Transform, &Parent, &mut GlobalTransform), Added<Emitter>>,
+    parents: Query<&Transform, Without<Emitter>>,
+) {
+    for (local, parent, mut global) in &mut emitters {
+        if let Ok(parent) = parents.get(parent.get()) {
+            *global = GlobalTransform::from(*parent).mul_transform(*local);
+        }
+    }
+}
+
+fn scale_count(count: usize, scale: f32) -> usize {
+    ((count as f32 * scale).round() as usize).max(1)
+}
+
+// spawn_boss and spawn_mook are written for Normal, everything else is scaled
+// from that as it comes in. This runs before track_max_health sees the health.
+fn apply_difficulty(
+    params: Res<MissionParams>,
+    mut emitters: Query<(&mut Emitter, &mut BulletSpawner), Added<Emitter>>,
+    mut health: Query<&mut Health, (With<EnemyMarker>, Added<Health>)>,
+) {
+    let difficulty = params.difficulty;
+    let speed = difficulty.bullet_speed();
+
+    for (mut emitter, mut spawner) in &mut emitters {
+        let interval = emitter.timer.duration().mul_f32(difficulty.fire_interval());
+        emitter.timer.set_duration(interval);
+
+        if let Some(normal) = &mut spawner.normal {
+            normal.velocity *= speed;
+        }
+        if let Some(stutter) = &mut spawner.stutter {
+            stutter.initial_velocity *= speed;
+        }
+        if let Some(wave) = &mut spawner.wave {
+            wave.true_velocity *= speed;
+        }
+    }
+
+    for mut health in &mut health {
+        health.0 = (health.0 as f32 * difficulty.enemy_health()).round() as u32;
+    }
+}
+
+/// The bullet count half of [`apply_difficulty`], for every emitter that
+/// fires volleys of some size.
+fn scale_volleys(
+    params: Res<MissionParams>,
+    mut aimed: Query<&mut CircularAimedEmitter, Added<CircularAimedEmitter>>,
+    mut waves: Query<&mut CircularWaveEmitter, Added<CircularWaveEmitter>>,
+    mut tentacles: Query<&mut TentacleEmitter, Added<TentacleEmitter>>,
+    mut divisive: Query<&mut DivisiveEmitter, Added<DivisiveEmitter>>,
+    mut sprays: Query<&mut SprayEmitter, Added<SprayEmitter>>,
+    mut rotating_sprays: Query<&mut RotatingSprayEmitter, Added<RotatingSprayEmitter>>,
+) {
+    let count = params.difficulty.bullet_count();
+
+    for mut emitter in &mut aimed {
+        emitter.count = scale_count(emitter.count, count);
+    }
+    for mut emitter in &mut waves {
+        emitter.count = scale_count(emitter.count, count);
+    }
+    for mut emitter in &mut tentacles {
+        emitter.count = scale_count(emitter.count, count);
+    }
+    for mut emitter in &mut divisive {
+        emitter.columns = scale_count(emitter.columns as usize, count) as u64;
+        emitter.rows = scale_count(emitter.rows as usize, count) as u64;
+    }
+    // sprays are a stream rather than volleys, so fire them faster instead
+    for mut emitter in &mut sprays {
+        emitter.firing_speed /= count;
+    }
+    for mut emitter in &mut rotating_sprays {
+        emitter.firing_speed /= count;
+    }
+}
+
+fn track_max_health(mut commands: Commands, added: Query<(Entity, &Health), Added<Health>>) {
+    for (ent, health) in &added {
+        commands.entity(ent).insert(MaxHealth(**health));
+    }
+}
+
+/// A small enemy flying in before the boss, with a single aimed emitter.
+pub fn spawn_mook<'a>(
+    commands: &'a mut Commands,
+    assets: &TouhouAssets,
+    pos: Vec2,
+    health: u32,
+) -> EntityCommands<'a> {
+    let bullet = BulletBundle {
+        collider: Collider::new(5.0),
+        sprite: Sprite {
+            image: assets.bullet1.clone(),
+            custom_size: Some(Vec2::splat(BULLET_SIZE)),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut mook = commands.spawn(EnemyBundle {
+        sprite: Sprite {
+            image: assets.tentacle.clone(),
+            custom_size: Some(Vec2::splat(48.0)),
+            ..Default::default()
+        },
+        transform: Transform::from_translation(pos.extend(0.0)),
+        collider: Collider::new(20.0),
+        health: Health(health),
+        ..Default::default()
+    });
+
+    mook.with_children(|parent| {
+        parent
+            .spawn(EmitterBundle {
+                transform: Transform::default(),
+                emitter: Emitter {
+                    timer: Timer::new(Duration::from_secs_f32(2.0), TimerMode::Repeating),
+                },
+                bullet_spawner: BulletSpawner::new(bullet).normal(Vec2::new(256.0, 0.0)),
+                active: Active(true),
+            })
+            .insert(SprayEmitter {
+                spray_width: 0.2,
+                firing_time: 0.3,
+                firing_speed: 0.1,
+                count: 0.0,
+            });
+    });
+
+    mook
+}
+
+pub fn spawn_boss(commands: &mut Commands, assets: &TouhouAssets, enemy: Enemies) {
     let red_girl_bullet = BulletBundle {
-        collider: Collider { radius: 5.0 },
+        collider: Collider::new(5.0),
         sprite: Sprite {
             image: assets.girl_bullet_sheet.clone(),
             custom_size: Some(Vec2::splat(BULLET_SIZE * 3.0)),
@@ -756,7 +1344,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
         ..Default::default()
     };
     let red_girl_bullet_2 = BulletBundle {
-        collider: Collider { radius: 5.0 },
+        collider: Collider::new(5.0),
         sprite: Sprite {
             image: assets.girl_bullet2_sheet.clone(),
             texture_atlas: Some(TextureAtlas {
@@ -770,7 +1358,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
         ..Default::default()
     };
     let lizard_bullet = BulletBundle {
-        collider: Collider { radius: 5.0 },
+        collider: Collider::new(5.0),
         sprite: Sprite {
             image: assets.lizard_bullet_sheet.clone(),
             custom_size: Some(Vec2::splat(BULLET_SIZE) * 2.0),
@@ -784,7 +1372,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
         ..Default::default()
     };
     let tentacle_bullet = BulletBundle {
-        collider: Collider { radius: 5.0 },
+        collider: Collider::new(5.0),
         sprite: Sprite {
             image: assets.bullet1.clone(),
             custom_size: Some(Vec2::splat(BULLET_SIZE)),
@@ -793,7 +1381,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
         ..Default::default()
     };
     let moon_girl_bullet = BulletBundle {
-        collider: Collider { radius: 5.0 },
+        collider: Collider::new(5.0),
         sprite: Sprite {
             image: assets.moongirl_bullet_sheet.clone(),
             custom_size: Some(Vec2::splat(BULLET_SIZE * 2.0)),
@@ -807,7 +1395,19 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
         ..Default::default()
     };
 
-    match params.enemy {
+    let laser_bullet = BulletBundle {
+        collider: Collider::new(5.0),
+        sprite: Sprite {
+            image: assets.beam.clone(),
+            anchor: Anchor::CenterLeft,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let cards = spellcards(enemy);
+
+    match enemy {
         Enemies::RedGirl => {
             let (mut em1, mut em2, mut em3) = (vec![], vec![], vec![]);
             commands
@@ -822,16 +1422,17 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                         ..Default::default()
                     },
                     animation: AnimatedSprite::new(0.1, 3, 0),
-                    transform: Transform::from_xyz(200.0, 0.0, 0.0),
-                    collider: Collider { radius: 150.0 },
+                    transform: Transform::from_translation(BOSS_ENTRY.extend(0.0)),
+                    collider: Collider::rect(Vec2::new(60.0, 130.0)),
                     health: Health(2000),
                     ..Default::default()
                 })
+                .insert(Boss)
                 .with_children(|parent| {
                     em1.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.05),
@@ -839,7 +1440,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(red_girl_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0))
+                                    .normal(Vec2::new(256.0, 0.0))
                                     .rotation(Vec2::ZERO, 0.0),
                                 active: Active(false),
                             })
@@ -852,7 +1453,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em1.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.25),
@@ -860,7 +1461,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(red_girl_bullet_2.clone())
-                                    .normal(Vec2::new(2.0, 0.0))
+                                    .normal(Vec2::new(128.0, 0.0))
                                     .homing(4.0, TAU / 8.0, Target::Player),
                                 active: Active(false),
                             })
@@ -874,7 +1475,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em2.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.5),
@@ -882,7 +1483,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(red_girl_bullet.clone())
-                                    .normal(Vec2::new(2.0, 0.0))
+                                    .normal(Vec2::new(128.0, 0.0))
                                     .rotation(Vec2::ZERO, TAU / 64.0),
                                 active: Active(false),
                             })
@@ -895,7 +1496,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em2.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.5),
@@ -903,7 +1504,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(red_girl_bullet.clone())
-                                    .normal(Vec2::new(2.0, 0.0))
+                                    .normal(Vec2::new(128.0, 0.0))
                                     .rotation(Vec2::ZERO, TAU / -64.0),
                                 active: Active(false),
                             })
@@ -916,7 +1517,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em3.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.1),
@@ -924,8 +1525,8 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(red_girl_bullet.clone())
-                                    .normal(Vec2::new(2.0, 0.0))
-                                    .wave(1.0, Vec2::new(2.0, 0.0)),
+                                    .normal(Vec2::new(128.0, 0.0))
+                                    .wave(1.0, Vec2::new(128.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(CircularWaveEmitter {
@@ -936,22 +1537,55 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                             })
                             .id(),
                     );
+                    em3.push(
+                        parent
+                            .spawn(EmitterBundle {
+                                transform: Transform::default(),
+                                emitter: Emitter {
+                                    timer: Timer::new(
+                                        Duration::from_secs_f32(3.0),
+                                        TimerMode::Repeating,
+                                    ),
+                                },
+                                bullet_spawner: BulletSpawner::new(laser_bullet.clone())
+                                    .laser(2400.0, 16.0, 0.8, 0.6, 0.0),
+                                active: Active(false),
+                            })
+                            .insert(LaserEmitter {
+                                count: 3,
+                                spread: TAU / 16.0,
+                                aimed: true,
+                            })
+                            .id(),
+                    );
                 });
-            commands.spawn(Spellcard {
-                emitters: em1,
-                start_time: 0.0,
-                end_time: 25.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em2,
-                start_time: 25.0,
-                end_time: 45.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em3,
-                start_time: 45.0,
-                end_time: 70.0,
-            });
+            commands.spawn(Spellcard::new(
+                cards,
+                0,
+                em1,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(200.0, 0.0),
+                    duration: 1.5,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                1,
+                em2,
+                Some(BossMovement::Wander {
+                    area: Rect::new(0.0, -300.0, 500.0, 300.0),
+                    interval: 3.0,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                2,
+                em3,
+                Some(BossMovement::FollowPlayer {
+                    x: 300.0,
+                    speed: 120.0,
+                }),
+            ));
         }
         Enemies::Tentacle => {
             let (mut em1, mut em2, mut em3) = (vec![], vec![], vec![]);
@@ -962,16 +1596,17 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                         custom_size: Some(Vec2::splat(150.0)),
                         ..Default::default()
                     },
-                    transform: Transform::from_xyz(200.0, 0.0, 0.0),
-                    collider: Collider { radius: 150.0 },
+                    transform: Transform::from_translation(BOSS_ENTRY.extend(0.0)),
+                    collider: Collider::new(60.0),
                     health: Health(1500),
                     ..Default::default()
                 })
+                .insert(Boss)
                 .with_children(|parent| {
                     em1.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(5.0),
@@ -979,7 +1614,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(tentacle_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(SprayEmitter {
@@ -993,7 +1628,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em2.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.05),
@@ -1001,7 +1636,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(tentacle_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(TentacleEmitter {
@@ -1013,7 +1648,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em3.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.4),
@@ -1021,7 +1656,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(tentacle_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(SprayEmitter {
@@ -1033,21 +1668,34 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                             .id(),
                     );
                 });
-            commands.spawn(Spellcard {
-                emitters: em1,
-                start_time: 0.0,
-                end_time: 25.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em2,
-                start_time: 25.0,
-                end_time: 45.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em3,
-                start_time: 45.0,
-                end_time: 70.0,
-            });
+            commands.spawn(Spellcard::new(
+                cards,
+                0,
+                em1,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(200.0, 0.0),
+                    duration: 1.5,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                1,
+                em2,
+                Some(BossMovement::FigureEight {
+                    center: Vec2::new(300.0, 0.0),
+                    size: Vec2::new(300.0, 600.0),
+                    period: 8.0,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                2,
+                em3,
+                Some(BossMovement::Wander {
+                    area: Rect::new(0.0, -300.0, 500.0, 300.0),
+                    interval: 2.0,
+                }),
+            ));
         }
         Enemies::Lizard => {
             let (mut em1, mut em2, mut em3) = (vec![], vec![], vec![]);
@@ -1063,16 +1711,17 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                         ..Default::default()
                     },
                     animation: AnimatedSprite::new(0.1, 3, 0),
-                    transform: Transform::from_xyz(200.0, 0.0, 0.0),
-                    collider: Collider { radius: 150.0 },
+                    transform: Transform::from_translation(BOSS_ENTRY.extend(0.0)),
+                    collider: Collider::rect(Vec2::new(90.0, 140.0)),
                     health: Health(2000),
                     ..Default::default()
                 })
+                .insert(Boss)
                 .with_children(|parent| {
                     em1.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.0),
@@ -1080,7 +1729,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(lizard_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(SprayEmitter {
@@ -1094,7 +1743,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em2.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.0),
@@ -1102,7 +1751,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(lizard_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(RotatingSprayEmitter {
@@ -1119,7 +1768,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em3.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(5.0),
@@ -1127,7 +1776,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(lizard_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0))
+                                    .normal(Vec2::new(256.0, 0.0))
                                     .rotation(Vec2::from((200.0, 0.0)), TAU / 16.0),
                                 active: Active(false),
                             })
@@ -1140,21 +1789,33 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                             .id(),
                     );
                 });
-            commands.spawn(Spellcard {
-                emitters: em1,
-                start_time: 0.0,
-                end_time: 25.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em2,
-                start_time: 25.0,
-                end_time: 45.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em3,
-                start_time: 45.0,
-                end_time: 70.0,
-            });
+            commands.spawn(Spellcard::new(
+                cards,
+                0,
+                em1,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(200.0, 0.0),
+                    duration: 1.5,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                1,
+                em2,
+                Some(BossMovement::FollowPlayer {
+                    x: 250.0,
+                    speed: 150.0,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                2,
+                em3,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(200.0, 0.0),
+                    duration: 1.0,
+                }),
+            ));
         }
         Enemies::MoonGirl => {
             let (mut em1, mut em2, mut em3, mut em4, mut em12, mut em34) =
@@ -1171,16 +1832,17 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                         ..Default::default()
                     },
                     animation: AnimatedSprite::new(0.1, 5, 0),
-                    transform: Transform::from_xyz(200.0, 0.0, 0.0),
-                    collider: Collider { radius: 150.0 },
+                    transform: Transform::from_translation(BOSS_ENTRY.extend(0.0)),
+                    collider: Collider::rect(Vec2::new(45.0, 80.0)),
                     health: Health(5000),
                     ..Default::default()
                 })
+                .insert(Boss)
                 .with_children(|parent| {
                     em1.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.05),
@@ -1188,7 +1850,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(5.0, 0.0)),
+                                    .normal(Vec2::new(320.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(DivisiveEmitter {
@@ -1200,7 +1862,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em2.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.01),
@@ -1208,7 +1870,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(-5.0, 0.0)),
+                                    .normal(Vec2::new(-320.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(FloodEmitter { spray: TAU / 16.0 })
@@ -1217,7 +1879,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em12.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.5),
@@ -1225,7 +1887,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(-2.0, 0.0))
+                                    .normal(Vec2::new(-128.0, 0.0))
                                     .rotation(Vec2::ZERO, TAU / 64.0),
                                 active: Active(false),
                             })
@@ -1238,7 +1900,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em3.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(1.0),
@@ -1246,7 +1908,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0)),
+                                    .normal(Vec2::new(256.0, 0.0)),
                                 active: Active(false),
                             })
                             .insert(RotatingSprayEmitter {
@@ -1260,10 +1922,36 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                             })
                             .id(),
                     );
+                    em3.push(
+                        parent
+                            .spawn(EmitterBundle {
+                                transform: Transform::default(),
+                                emitter: Emitter {
+                                    timer: Timer::new(
+                                        Duration::from_secs_f32(5.0),
+                                        TimerMode::Repeating,
+                                    ),
+                                },
+                                bullet_spawner: BulletSpawner::new(laser_bullet.clone()).laser(
+                                    2400.0,
+                                    24.0,
+                                    1.0,
+                                    3.0,
+                                    TAU / 16.0,
+                                ),
+                                active: Active(false),
+                            })
+                            .insert(LaserEmitter {
+                                count: 4,
+                                spread: TAU / 4.0,
+                                aimed: false,
+                            })
+                            .id(),
+                    );
                     em4.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.05),
@@ -1271,8 +1959,9 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0))
-                                    .rotation(Vec2::ZERO, TAU / 16.0),
+                                    .normal(Vec2::new(256.0, 0.0))
+                                    .rotation(Vec2::ZERO, TAU / 16.0)
+                                    .track_emitter(),
                                 active: Active(false),
                             })
                             .insert(CircularAimedEmitter {
@@ -1284,7 +1973,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em4.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(0.05),
@@ -1292,8 +1981,9 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0))
-                                    .rotation(Vec2::ZERO, TAU / -16.0),
+                                    .normal(Vec2::new(256.0, 0.0))
+                                    .rotation(Vec2::ZERO, TAU / -16.0)
+                                    .track_emitter(),
                                 active: Active(false),
                             })
                             .insert(CircularAimedEmitter {
@@ -1305,7 +1995,7 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                     em34.push(
                         parent
                             .spawn(EmitterBundle {
-                                transform: Transform::from_xyz(200.0, 0.0, 0.0),
+                                transform: Transform::default(),
                                 emitter: Emitter {
                                     timer: Timer::new(
                                         Duration::from_secs_f32(4.0),
@@ -1313,8 +2003,8 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                                     ),
                                 },
                                 bullet_spawner: BulletSpawner::new(moon_girl_bullet.clone())
-                                    .normal(Vec2::new(4.0, 0.0))
-                                    .stutter(1.0, Vec2::new(4.0, 0.0), false)
+                                    .normal(Vec2::new(256.0, 0.0))
+                                    .stutter(1.0, Vec2::new(256.0, 0.0), false)
                                     .homing(3.0, TAU / 3.0, Target::Player),
                                 active: Active(false),
                             })
@@ -1325,35 +2015,56 @@ pub fn spawn_enemy(mut commands: Commands, assets: Res<TouhouAssets>, params: Re
                             .id(),
                     );
                 });
-            commands.spawn(Spellcard {
-                emitters: em1,
-                start_time: 0.0,
-                end_time: 25.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em2,
-                start_time: 25.0,
-                end_time: 45.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em3,
-                start_time: 45.0,
-                end_time: 70.0,
-            });
-            commands.spawn(Spellcard {
-                emitters: em4,
-                start_time: 70.0,
-                end_time: 100.0,
-            });
+            commands.spawn(Spellcard::new(
+                cards,
+                0,
+                em1,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(200.0, 0.0),
+                    duration: 1.5,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                1,
+                em2,
+                Some(BossMovement::Wander {
+                    area: Rect::new(100.0, -400.0, 600.0, 400.0),
+                    interval: 2.5,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                2,
+                em3,
+                Some(BossMovement::MoveTo {
+                    to: Vec2::new(300.0, 0.0),
+                    duration: 2.0,
+                }),
+            ));
+            commands.spawn(Spellcard::new(
+                cards,
+                3,
+                em4,
+                Some(BossMovement::FigureEight {
+                    center: Vec2::new(250.0, 0.0),
+                    size: Vec2::new(300.0, 500.0),
+                    period: 10.0,
+                }),
+            ));
             commands.spawn(Spellcard {
                 emitters: em12,
                 start_time: 0.0,
                 end_time: 45.0,
+                movement: None,
+                index: None,
             });
             commands.spawn(Spellcard {
                 emitters: em34,
                 start_time: 45.0,
                 end_time: 100.0,
+                movement: None,
+                index: None,
             });
         }
         default => {}
@@ -0,0 +1,128 @@
+use std::f32::consts::TAU;
+
+use bullet::{AltFire, Weapon};
+use input::{InputFrame, input_pressed};
+use pool::BulletPool;
+
+use super::*;
+
+/// How many familiars each equipped [`Tech::Familiars`] brings along.
+pub const FAMILIARS_PER_TECH: usize = 2;
+const FAMILIAR_SIZE: f32 = 40.0;
+const ORBIT_RADIUS: f32 = 90.0;
+/// in radians/s
+const ORBIT_SPEED: f32 = 2.5;
+/// where the familiars line up while focused, relative to the ship
+const FORMATION_AHEAD: f32 = 70.0;
+const FORMATION_SPACING: f32 = 36.0;
+/// how quickly familiars catch up with where they should be, per second
+const FOLLOW_RATE: f32 = 12.0;
+
+pub fn familiar_plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        (
+            move_familiars,
+            fire_familiars.run_if(input_pressed(InputFrame::FIRE)),
+        )
+            .chain()
+            .in_set(TouhouSets::Gameplay),
+    );
+}
+
+/// An option circling the player ship with a gun of its own. They're
+/// children of the player, so their [`Transform`] is relative to it.
+#[derive(Component)]
+pub struct Familiar {
+    index: usize,
+    count: usize,
+    /// where on the orbit it currently is
+    angle: f32,
+}
+
+pub fn spawn_familiars(
+    player: &mut ChildBuilder,
+    assets: &TouhouAssets,
+    weapons: &[weapons::WeaponDef],
+    count: usize,
+    stats: &ShipStats,
+    salted: bool,
+    phasing: bool,
+) {
+    for index in 0..count {
+        let angle = index as f32 * TAU / count as f32;
+        let mut familiar = player.spawn((
+            Familiar {
+                index,
+                count,
+                angle,
+            },
+            Sprite {
+                image: assets.familiar.clone(),
+                custom_size: Some(Vec2::splat(FAMILIAR_SIZE)),
+                ..default()
+            },
+            Transform::from_translation((Vec2::from_angle(angle) * ORBIT_RADIUS).extend(0.1)),
+        ));
+
+        // with more than one gun in the file, the familiars take turns
+        if !weapons.is_empty() {
+            let mut weapon = weapons[index % weapons.len()].build(assets).tuned(stats);
+            // same as the ship's guns, from the group the familiars are in
+            weapon.salted |= salted;
+            weapon.phasing |= phasing;
+            familiar.insert(weapon);
+        }
+    }
+}
+
+fn move_familiars(
+    time: Res<Time>,
+    player: Option<PlayerQ<Has<AltFire>>>,
+    mut familiars: Query<(&mut Familiar, &mut Transform)>,
+) {
+    let Some(focused) = player.map(|p| *p) else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let follow = 1.0 - (-FOLLOW_RATE * dt).exp();
+
+    for (mut familiar, mut trans) in &mut familiars {
+        // keep orbiting while in formation, so they fan back out smoothly
+        familiar.angle = (familiar.angle + ORBIT_SPEED * dt) % TAU;
+
+        let target = if focused {
+            let spread = familiar.index as f32 - (familiar.count - 1) as f32 / 2.0;
+            Vec2::new(FORMATION_AHEAD, spread * FORMATION_SPACING)
+        } else {
+            Vec2::from_angle(familiar.angle) * ORBIT_RADIUS
+        };
+
+        let pos = trans.translation.xy().lerp(target, follow);
+        trans.translation = pos.extend(trans.translation.z);
+    }
+}
+
+fn fire_familiars(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut pool: ResMut<BulletPool>,
+    mut familiars: Query<(&Transform, &mut Weapon), With<Familiar>>,
+    player: PlayerQ<(&Transform, &mut Ammo)>,
+) {
+    let (player_trans, mut ammo) = player.into_inner();
+
+    for (trans, mut weapon) in &mut familiars {
+        weapon.timer.tick(time.delta());
+
+        if !weapon.timer.just_finished() || **ammo < weapon.ammo_cost {
+            continue;
+        }
+
+        **ammo -= weapon.ammo_cost;
+
+        let pos = player_trans.translation.xy() + trans.translation.xy();
+        weapon.spawn_bullet(&mut commands, &mut pool, pos);
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+pub fn input_plugin(app: &mut App) {
+    app.init_resource::<TouhouInput>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            (|mut input: ResMut<TouhouInput>| *input = default()).in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(
+            PreUpdate,
+            buffer_presses
+                .run_if(in_state(GameState::Touhou).and(in_state(pause::PauseState::Running))),
+        );
+}
+
+/// The player's input for a single fixed update tick, one bit per button.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct InputFrame(pub u8);
+
+impl InputFrame {
+    pub const UP: u8 = 1 << 0;
+    pub const DOWN: u8 = 1 << 1;
+    pub const LEFT: u8 = 1 << 2;
+    pub const RIGHT: u8 = 1 << 3;
+    pub const FIRE: u8 = 1 << 4;
+    pub const FOCUS: u8 = 1 << 5;
+    pub const BOMB: u8 = 1 << 6;
+
+    pub fn contains(self, bits: u8) -> bool {
+        self.0 & bits == bits
+    }
+
+    fn set(&mut self, bits: u8, value: bool) {
+        if value {
+            self.0 |= bits;
+        } else {
+            self.0 &= !bits;
+        }
+    }
+
+    pub fn from_actions(actions: &ActionInput) -> Self {
+        let mut frame = Self::default();
+
+        frame.set(Self::UP, actions.pressed(Action::MoveUp));
+        frame.set(Self::DOWN, actions.pressed(Action::MoveDown));
+        frame.set(Self::LEFT, actions.pressed(Action::MoveLeft));
+        frame.set(Self::RIGHT, actions.pressed(Action::MoveRight));
+        frame.set(Self::FIRE, actions.pressed(Action::Fire));
+        frame.set(Self::FOCUS, actions.pressed(Action::Focus));
+
+        frame
+    }
+}
+
+/// What the gameplay systems see of the player's input. Everything reading
+/// input during a fight goes through this instead of the keyboard, so a fight
+/// can be recorded and played back tick for tick.
+#[derive(Resource, Default)]
+pub struct TouhouInput {
+    current: InputFrame,
+    previous: InputFrame,
+    // presses that happened between two fixed ticks, so they aren't lost
+    buffered: InputFrame,
+}
+
+impl TouhouInput {
+    pub fn advance(&mut self, frame: InputFrame) {
+        self.previous = self.current;
+        self.current = frame;
+    }
+
+    pub fn take_buffered(&mut self) -> InputFrame {
+        std::mem::take(&mut self.buffered)
+    }
+
+    pub fn pressed(&self, bits: u8) -> bool {
+        self.current.contains(bits)
+    }
+
+    pub fn just_pressed(&self, bits: u8) -> bool {
+        self.current.contains(bits) && !self.previous.contains(bits)
+    }
+
+    pub fn just_released(&self, bits: u8) -> bool {
+        !self.current.contains(bits) && self.previous.contains(bits)
+    }
+
+    /// Movement direction as -1, 0 or 1 on each axis.
+    pub fn movement(&self) -> Vec2 {
+        let axis = |pos, neg| self.pressed(pos) as i32 as f32 - self.pressed(neg) as i32 as f32;
+
+        Vec2::new(
+            axis(InputFrame::RIGHT, InputFrame::LEFT),
+            axis(InputFrame::UP, InputFrame::DOWN),
+        )
+    }
+}
+
+pub fn input_pressed(bits: u8) -> impl Fn(Res<TouhouInput>) -> bool {
+    move |input: Res<TouhouInput>| input.pressed(bits)
+}
+
+pub fn input_just_pressed(bits: u8) -> impl Fn(Res<TouhouInput>) -> bool {
+    move |input: Res<TouhouInput>| input.just_pressed(bits)
+}
+
+fn buffer_presses(actions: ActionInput, mut input: ResMut<TouhouInput>) {
+    if actions.just_pressed(Action::Bomb) {
+        input.buffered.set(InputFrame::BOMB, true);
+    }
+}
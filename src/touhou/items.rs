@@ -0,0 +1,214 @@
+use rand::prelude::*;
+use std::f32::consts::PI;
+
+use enemy::{EnemyMarker, Health};
+use replay::{RngStream, TouhouRng};
+
+use super::*;
+
+// an enemy drops a handful of items every time it loses this much health
+const DROP_EVERY_HP: u32 = 250;
+const ITEMS_PER_DROP: usize = 3;
+
+const BOMB_PIECES_PER_BOMB: u32 = 5;
+const AMMO_PER_ITEM: u32 = 100;
+
+// all speeds in units/s
+const ITEM_POP_SPEED: f32 = 300.0;
+const ITEM_DRIFT: Vec2 = Vec2::new(-150.0, 0.0);
+const ITEM_MAGNET_SPEED: f32 = 1200.0;
+
+const ITEM_SIZE: f32 = 40.0;
+const ITEM_PICKUP_RADIUS: f32 = 30.0;
+const ITEM_MAGNET_RADIUS: f32 = 100.0;
+
+// fraction of the gameplay area, counted from the left, past which the player
+// pulls in every item on screen
+const AUTO_COLLECT_LINE: f32 = 0.6;
+
+pub fn item_plugin(app: &mut App) {
+    app.init_resource::<BombPieces>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            (|mut pieces: ResMut<BombPieces>| pieces.0 = 0).in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(
+            FixedUpdate,
+            (drop_enemy_items, move_items, collect_items)
+                .chain()
+                .in_set(TouhouSets::Gameplay),
+        );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    Ammo,
+    BombPiece,
+    ExtraLife,
+    MagicDust,
+}
+
+impl ItemKind {
+    fn random(rng: &mut impl Rng) -> Self {
+        match rng.random_range(0..100) {
+            0..3 => ItemKind::ExtraLife,
+            3..15 => ItemKind::BombPiece,
+            15..50 => ItemKind::Ammo,
+            _ => ItemKind::MagicDust,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Item {
+    kind: ItemKind,
+    velocity: Vec2,
+    magnetized: bool,
+}
+
+/// Health an enemy had when it last dropped items.
+#[derive(Component, Default)]
+pub struct ItemDrops {
+    last_health: Option<u32>,
+}
+
+#[derive(Resource, Default)]
+struct BombPieces(u32);
+
+pub fn spawn_item(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    assets: &TouhouAssets,
+    kind: ItemKind,
+    pos: Vec2,
+) {
+    let image = match kind {
+        ItemKind::Ammo => assets.item_ammo.clone(),
+        ItemKind::BombPiece => assets.item_bomb.clone(),
+        ItemKind::ExtraLife => assets.item_life.clone(),
+        ItemKind::MagicDust => assets.item_dust.clone(),
+    };
+
+    let dir = Vec2::from_angle(rng.random_range(-PI / 2.0..=PI / 2.0));
+
+    commands.spawn((
+        Item {
+            kind,
+            velocity: dir * ITEM_POP_SPEED,
+            magnetized: false,
+        },
+        Sprite {
+            image,
+            custom_size: Some(Vec2::splat(ITEM_SIZE)),
+            ..Default::default()
+        },
+        Transform::from_translation(pos.extend(-0.2)),
+        TouhouMarker,
+    ));
+}
+
+fn drop_enemy_items(
+    mut commands: Commands,
+    assets: Res<TouhouAssets>,
+    mut rng: ResMut<TouhouRng>,
+    mut enemies: Query<(&Transform, &Health, &mut ItemDrops), With<EnemyMarker>>,
+) {
+    let rng = rng.stream(RngStream::Items);
+
+    for (trans, health, mut drops) in &mut enemies {
+        let last = drops.last_health.get_or_insert(**health);
+
+        while *last >= **health + DROP_EVERY_HP {
+            *last -= DROP_EVERY_HP;
+
+            for _ in 0..ITEMS_PER_DROP {
+                let kind = ItemKind::random(rng);
+                spawn_item(&mut commands, rng, &assets, kind, trans.translation.xy());
+            }
+        }
+    }
+}
+
+fn move_items(
+    mut commands: Commands,
+    time: Res<Time>,
+    area: Res<GameplayRect>,
+    player: Option<Single<&Transform, (PlayerFilter, Without<Item>)>>,
+    mut items: Query<(Entity, &mut Item, &mut Transform)>,
+) {
+    let player = player.map(|p| p.translation.xy());
+    let rect = area.rect;
+
+    let collect_all = player.is_some_and(|p| p.x > rect.min.x + rect.width() * AUTO_COLLECT_LINE);
+
+    for (ent, mut item, mut trans) in &mut items {
+        let pos = trans.translation.xy();
+
+        if let Some(player) = player {
+            if collect_all || pos.distance(player) < ITEM_MAGNET_RADIUS {
+                item.magnetized = true;
+            }
+
+            if item.magnetized {
+                item.velocity = (player - pos).normalize_or_zero() * ITEM_MAGNET_SPEED;
+            }
+        }
+
+        if !item.magnetized {
+            // settle from the initial pop into a slow drift towards the player's side
+            item.velocity = item
+                .velocity
+                .lerp(ITEM_DRIFT, (time.delta_secs() * 2.0).min(1.0));
+        }
+
+        trans.translation += (item.velocity * time.delta_secs()).extend(0.0);
+
+        if trans.translation.x < rect.min.x - ITEM_SIZE {
+            commands.entity(ent).despawn();
+        }
+    }
+}
+
+/// Where the player is and everything an item can top up.
+type Collector = (
+    &'static Transform,
+    &'static Collider,
+    &'static mut Ammo,
+    &'static mut Life,
+    &'static mut Bombs,
+);
+
+fn collect_items(
+    mut commands: Commands,
+    mut pieces: ResMut<BombPieces>,
+    mut result: ResMut<MissionResult>,
+    player: Option<PlayerQ<Collector>>,
+    items: Query<(Entity, &Item, &Transform)>,
+) {
+    let Some((trans, coll, mut ammo, mut life, mut bombs)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+
+    let pickup = coll.inflated(ITEM_PICKUP_RADIUS).shape(trans);
+
+    for (ent, item, i_trans) in &items {
+        if !pickup.hits(&Shape::Circle(Circle::new(0.0, i_trans.translation.xy()))) {
+            continue;
+        }
+
+        match item.kind {
+            ItemKind::Ammo => **ammo += AMMO_PER_ITEM,
+            ItemKind::BombPiece => {
+                pieces.0 += 1;
+                if pieces.0 >= BOMB_PIECES_PER_BOMB {
+                    pieces.0 -= BOMB_PIECES_PER_BOMB;
+                    **bombs += 1;
+                }
+            }
+            ItemKind::ExtraLife => **life += 1,
+            ItemKind::MagicDust => result.magic_dust += 1,
+        }
+
+        commands.entity(ent).despawn();
+    }
+}
@@ -0,0 +1,230 @@
+use super::*;
+
+pub fn pause_plugin(app: &mut App) {
+    app.init_state::<PauseState>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            (|mut pause: ResMut<NextState<PauseState>>| pause.set(PauseState::Running))
+                .in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(
+            Update,
+            toggle_pause
+                .run_if(in_state(GameState::Touhou).and(in_state(MissionState::Ongoing)))
+                .run_if(action_just_pressed(Action::Pause)),
+        )
+        .add_systems(OnEnter(PauseState::Paused), spawn_pause_menu)
+        .add_systems(OnExit(PauseState::Paused), despawn_pause_menu)
+        .add_systems(
+            Update,
+            pause_buttons.run_if(in_state(GameState::Touhou).and(in_state(PauseState::Paused))),
+        );
+}
+
+/// While paused none of the [`TouhouSets::Gameplay`] systems run.
+#[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
+#[derive(Component)]
+struct PauseMenu;
+
+#[derive(Component, Clone, Copy)]
+enum PauseButton {
+    Resume,
+    Restart,
+    Retreat,
+}
+
+const BUTTON_COLOR: Color = Color::srgba(0.1, 0.1, 0.2, 0.9);
+const BUTTON_HOVER_COLOR: Color = Color::srgba(0.3, 0.3, 0.5, 0.9);
+
+fn toggle_pause(state: Res<State<PauseState>>, mut next: ResMut<NextState<PauseState>>) {
+    next.set(match state.get() {
+        PauseState::Running => PauseState::Paused,
+        PauseState::Paused => PauseState::Running,
+    });
+}
+
+fn spawn_pause_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/Pixelfont/slkscr.ttf");
+
+    commands
+        .spawn((
+            TouhouMarker,
+            PauseMenu,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(20.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(2),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Paused"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            for (button, label) in [
+                (PauseButton::Resume, "Resume"),
+                (PauseButton::Restart, "Restart"),
+                (PauseButton::Retreat, "Retreat"),
+            ] {
+                parent
+                    .spawn((
+                        Button,
+                        button,
+                        Node {
+                            width: Val::Px(320.0),
+                            height: Val::Px(64.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_COLOR),
+                    ))
+                    .with_child((
+                        Text::new(label),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 33.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.9)),
+                    ));
+            }
+        });
+}
+
+fn despawn_pause_menu(mut commands: Commands, menu: Query<Entity, With<PauseMenu>>) {
+    for ent in &menu {
+        commands.entity(ent).despawn_recursive();
+    }
+}
+
+type Interacted = (Changed<Interaction>, With<Button>);
+
+fn pause_buttons(
+    mut commands: Commands,
+    mut next_pause: ResMut<NextState<PauseState>>,
+    mut mission_state: ResMut<NextState<MissionState>>,
+    mut buttons: Query<(&Interaction, &PauseButton, &mut BackgroundColor), Interacted>,
+) {
+    for (interaction, button, mut color) in &mut buttons {
+        match *interaction {
+            Interaction::Pressed => {
+                next_pause.set(PauseState::Running);
+
+                match button {
+                    PauseButton::Resume => {}
+                    PauseButton::Restart => commands.queue(restart_mission),
+                    PauseButton::Retreat => mission_state.set(MissionState::Retreat),
+                }
+            }
+            Interaction::Hovered => color.0 = BUTTON_HOVER_COLOR,
+            Interaction::None => color.0 = BUTTON_COLOR,
+        }
+    }
+}
+
+fn restart_mission(world: &mut World) {
+    // starting over doesn't undo the hull damage or give back the rounds
+    // fired, the next attempt goes up with whatever is left
+    let result = world.resource::<MissionResult>();
+    let lives_lost = result.lives_lost;
+    let spent = result.ammo_loaded.saturating_sub(result.ammo_left);
+    let returned = result.ammo_left.saturating_sub(result.ammo_loaded);
+
+    let mut params = world.resource_mut::<MissionParams>();
+    params.hull = params.hull.saturating_sub(lives_lost as usize).max(1);
+    params.ammo = params.ammo.saturating_sub(spent).saturating_add(returned);
+
+    // OnExit/OnEnter don't run when going from a state to itself, so tear the
+    // fight down and set it back up by hand
+    world.run_schedule(OnExit(GameState::Touhou));
+    world.run_schedule(OnEnter(GameState::Touhou));
+
+    // the hangar takes everything lost since launch off the craft and stock
+    let mut result = world.resource_mut::<MissionResult>();
+    result.lives_lost += lives_lost;
+    result.ammo_loaded = (result.ammo_loaded + spent).saturating_sub(returned);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restartable_world() -> World {
+        let mut world = World::new();
+        world.insert_resource(MissionParams {
+            loadout: vec![],
+            enemy: Enemies::RedGirl,
+            map: Map::Day,
+            difficulty: Difficulty::Normal,
+            practice: None,
+            hull: 3,
+            ammo: 1000,
+            perks: vec![],
+        });
+        world.init_resource::<MissionResult>();
+        world.add_schedule(Schedule::new(OnExit(GameState::Touhou)));
+
+        // stands in for config_loadout, which fills the magazine from stock
+        let load = |params: Res<MissionParams>, mut result: ResMut<MissionResult>| {
+            result.ammo_loaded = params.ammo.min(600);
+            result.ammo_left = result.ammo_loaded;
+        };
+        let mut enter = Schedule::new(OnEnter(GameState::Touhou));
+        enter.add_systems((score::reset_score, load).chain());
+        world.add_schedule(enter);
+        world
+    }
+
+    #[test]
+    fn restarting_keeps_hull_damage() {
+        let mut world = restartable_world();
+        world.resource_mut::<MissionResult>().lives_lost = 2;
+
+        restart_mission(&mut world);
+
+        assert_eq!(world.resource::<MissionParams>().hull, 1);
+        assert_eq!(world.resource::<MissionResult>().lives_lost, 2);
+
+        // the craft can't be worn down past its last point of hull
+        world.resource_mut::<MissionResult>().lives_lost += 1;
+        restart_mission(&mut world);
+
+        assert_eq!(world.resource::<MissionParams>().hull, 1);
+        assert_eq!(world.resource::<MissionResult>().lives_lost, 3);
+    }
+
+    #[test]
+    fn restarting_keeps_rounds_fired() {
+        let mut world = restartable_world();
+        world.run_schedule(OnEnter(GameState::Touhou));
+        world.resource_mut::<MissionResult>().ammo_left = 200;
+
+        restart_mission(&mut world);
+
+        // 400 of the 1000 in stock are gone, the rest fills the magazine again
+        assert_eq!(world.resource::<MissionParams>().ammo, 600);
+        let result = world.resource::<MissionResult>();
+        assert_eq!(result.ammo_loaded, 1000);
+        assert_eq!(result.ammo_left, 600);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+
+use bullet::{
+    BulletBundle, BulletCounters, BulletMarker, DelayedBullet, EnemyBullets, HomingBullet,
+    LaserBullet, NormalBullet, Phasing, PlayerBullet, PlayerBullets, RotatingBullet, Salted,
+    StutterBullet, WaveBullet,
+};
+
+use super::{score::Grazed, *};
+
+const DEFAULT_POOL_CAP: usize = 4096;
+
+pub fn pool_plugin(app: &mut App) {
+    app.insert_resource(BulletPool::with_cap(DEFAULT_POOL_CAP))
+        .add_systems(OnEnter(GameState::Touhou), spawn_pool_stats)
+        .add_systems(
+            Update,
+            update_pool_stats.run_if(in_state(GameState::Touhou)),
+        )
+        .add_systems(OnExit(GameState::Touhou), clear_pool);
+}
+
+/// Recycles bullet entities instead of spawning and despawning one per shot.
+///
+/// Released bullets are hidden and stripped of their marker and movement
+/// components, so none of the bullet systems see them until they're handed
+/// out again by [`BulletPool::spawn`].
+#[derive(Resource)]
+pub struct BulletPool {
+    free: Vec<Entity>,
+    pooled: HashSet<Entity>,
+    /// how many hidden bullets we keep around, anything above is despawned
+    pub cap: usize,
+    pub allocated: usize,
+    pub recycled: usize,
+}
+
+impl BulletPool {
+    pub fn with_cap(cap: usize) -> Self {
+        Self {
+            free: Vec::with_capacity(cap),
+            pooled: HashSet::with_capacity(cap),
+            cap,
+            allocated: 0,
+            recycled: 0,
+        }
+    }
+
+    pub fn pooled(&self) -> usize {
+        self.free.len()
+    }
+
+    pub fn spawn<'a>(
+        &mut self,
+        commands: &'a mut Commands,
+        bullet: BulletBundle,
+    ) -> EntityCommands<'a> {
+        if let Some(ent) = self.free.pop() {
+            self.pooled.remove(&ent);
+            self.recycled += 1;
+
+            let mut ent = commands.entity(ent);
+            ent.insert((bullet, Visibility::Inherited));
+            ent
+        } else {
+            self.allocated += 1;
+            commands.spawn(bullet)
+        }
+    }
+
+    /// Returns the bullet to the pool, or despawns it if the pool is full.
+    /// Releasing the same bullet twice is fine and does nothing.
+    pub fn release(&mut self, commands: &mut Commands, ent: Entity) {
+        if self.pooled.contains(&ent) {
+            return;
+        }
+
+        let Some(mut bullet) = commands.get_entity(ent) else {
+            return;
+        };
+
+        if self.free.len() >= self.cap {
+            bullet.try_despawn();
+            return;
+        }
+
+        bullet
+            .remove::<(
+                BulletMarker,
+                NormalBullet,
+                RotatingBullet,
+                HomingBullet,
+                StutterBullet,
+                WaveBullet,
+                DelayedBullet,
+                LaserBullet,
+            )>()
+            .remove::<(PlayerBullet, Salted, Phasing, Grazed)>()
+            .insert(Visibility::Hidden);
+
+        self.pooled.insert(ent);
+        self.free.push(ent);
+    }
+
+    fn clear(&mut self) {
+        self.free.clear();
+        self.pooled.clear();
+        self.allocated = 0;
+        self.recycled = 0;
+    }
+}
+
+fn clear_pool(mut pool: ResMut<BulletPool>) {
+    // the entities themselves carry TouhouMarker and get nuked with the rest
+    pool.clear();
+}
+
+#[derive(Component)]
+struct PoolStats;
+
+fn spawn_pool_stats(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TouhouMarker,
+        PoolStats,
+        Text::new(""),
+        TextFont {
+            font: asset_server.load("fonts/Pixelfont/slkscr.ttf"),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.9, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(5.0),
+            top: Val::Px(5.0),
+            display: Display::None,
+            ..default()
+        },
+        ZIndex(1),
+    ));
+}
+
+fn update_pool_stats(
+    pool: Res<BulletPool>,
+    counters: Res<BulletCounters>,
+    gizmos: Res<ShowGizmos>,
+    player_bullets: Query<(), PlayerBullets>,
+    enemy_bullets: Query<(), EnemyBullets>,
+    mut stats: Query<(&mut Text, &mut Node), With<PoolStats>>,
+) {
+    for (mut text, mut node) in &mut stats {
+        node.display = if gizmos.enabled {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        **text = format!(
+            "Bullets player: {} enemy: {} pooled: {}/{}\nallocated: {} recycled: {}\nculled: {} expired: {}",
+            player_bullets.iter().count(),
+            enemy_bullets.iter().count(),
+            pool.pooled(),
+            pool.cap,
+            pool.allocated,
+            pool.recycled,
+            counters.out_of_bounds,
+            counters.expired,
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use input::{InputFrame, TouhouInput};
+use rand::{SeedableRng, rngs::StdRng};
+
+use super::*;
+
+pub fn replay_plugin(app: &mut App) {
+    app.insert_resource(TouhouRng::new(0))
+        .init_resource::<Replays>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            start_replay.in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(FixedPreUpdate, sample_input.in_set(TouhouSets::Gameplay))
+        .add_systems(OnExit(GameState::Touhou), restore_timestep)
+        .add_systems(OnEnter(MissionState::Success), store_replay)
+        .add_systems(OnEnter(MissionState::Fail), store_replay);
+}
+
+/// Who gets their own rng stream, see [`TouhouRng`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RngStream {
+    Spray,
+    RotatingSpray,
+    Flood,
+    Items,
+    Bomb,
+    Boss,
+}
+
+/// All randomness during a fight comes from here, seeded from the replay.
+///
+/// Every consumer draws from its own stream, so the order systems happen to
+/// run in doesn't change what they get.
+#[derive(Resource)]
+pub struct TouhouRng {
+    seed: u64,
+    streams: HashMap<RngStream, StdRng>,
+}
+
+impl TouhouRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            streams: HashMap::new(),
+        }
+    }
+
+    pub fn stream(&mut self, stream: RngStream) -> &mut StdRng {
+        let seed = self.seed;
+
+        self.streams.entry(stream).or_insert_with(|| {
+            StdRng::seed_from_u64(seed ^ (stream as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        })
+    }
+}
+
+/// The fight currently being recorded or played back.
+#[derive(Resource)]
+pub struct ActiveReplay {
+    replay: Replay,
+    cursor: usize,
+    playback: bool,
+}
+
+fn start_replay(
+    mut commands: Commands,
+    params: Res<MissionParams>,
+    replays: Res<Replays>,
+    settings: Res<Settings>,
+    mut time: ResMut<Time<Fixed>>,
+) {
+    let active = match &replays.playback {
+        Some(replay) => ActiveReplay {
+            replay: replay.clone(),
+            cursor: 0,
+            playback: true,
+        },
+        None => ActiveReplay {
+            replay: Replay {
+                seed: rand::random(),
+                hz: settings.fixed_hz,
+                params: params.clone(),
+                inputs: Vec::new(),
+                score: 0,
+            },
+            cursor: 0,
+            playback: false,
+        },
+    };
+
+    // inputs are per tick, so a replay has to run at the rate it was recorded at
+    time.set_timestep_hz(active.replay.hz);
+
+    commands.insert_resource(TouhouRng::new(active.replay.seed));
+    commands.insert_resource(active);
+}
+
+pub fn restore_timestep(settings: Res<Settings>, mut time: ResMut<Time<Fixed>>) {
+    time.set_timestep_hz(settings.fixed_hz);
+}
+
+pub fn sample_input(
+    actions: ActionInput,
+    mut input: ResMut<TouhouInput>,
+    mut active: ResMut<ActiveReplay>,
+) {
+    let frame = if active.playback {
+        let frame = active.replay.inputs.get(active.cursor).copied();
+        active.cursor += 1;
+        InputFrame(frame.unwrap_or_default())
+    } else {
+        let frame = InputFrame(InputFrame::from_actions(&actions).0 | input.take_buffered().0);
+        active.replay.inputs.push(frame.0);
+        frame
+    };
+
+    input.advance(frame);
+}
+
+fn store_replay(
+    active: Option<Res<ActiveReplay>>,
+    result: Res<MissionResult>,
+    mut replays: ResMut<Replays>,
+) {
+    let Some(active) = active else {
+        return;
+    };
+
+    if active.playback {
+        return;
+    }
+
+    let mut replay = active.replay.clone();
+    replay.score = result.score;
+    replays.recorded.push(replay);
+}
@@ -0,0 +1,131 @@
+use bullet::{EnemyBullets, LaserBullet, LifeLost, Lifetime};
+
+use super::*;
+
+// how far outside the player's collider a bullet still counts as a graze
+const GRAZE_MARGIN: f32 = 30.0;
+
+pub const GRAZE_POINTS: u64 = 50;
+pub const DAMAGE_POINTS: u64 = 10;
+pub const SPELLCARD_POINTS: u64 = 10_000;
+pub const SURVIVAL_POINTS_PER_SEC: f32 = 100.0;
+
+pub fn score_plugin(app: &mut App) {
+    app.init_resource::<MissionResult>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            (reset_score, spawn_score_hud).in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                check_grazes,
+                score_survival,
+                count_lives_lost,
+                track_ammo_left,
+            )
+                .in_set(TouhouSets::Gameplay),
+        )
+        .add_systems(Update, update_score_hud.run_if(in_state(GameState::Touhou)));
+}
+
+/// Put on an enemy bullet once it has been grazed, so it only scores once.
+#[derive(Component, Default)]
+pub struct Grazed;
+
+#[derive(Component)]
+struct ScoreCount;
+
+pub fn reset_score(mut result: ResMut<MissionResult>) {
+    *result = default();
+}
+
+type Grazeable = (
+    Entity,
+    &'static Transform,
+    &'static Collider,
+    &'static Lifetime,
+    Option<&'static LaserBullet>,
+);
+
+fn check_grazes(
+    mut commands: Commands,
+    mut result: ResMut<MissionResult>,
+    player: Option<PlayerQ<(&Transform, &Collider)>>,
+    bullets: Query<Grazeable, (EnemyBullets, Without<Grazed>)>,
+) {
+    let Some((trans, coll)) = player.map(|p| p.into_inner()) else {
+        return;
+    };
+
+    let hitbox = coll.shape(trans);
+    let graze = coll.inflated(GRAZE_MARGIN).shape(trans);
+
+    for (ent, b_trans, b_coll, lifetime, laser) in &bullets {
+        // warning lines can't be grazed, there's nothing there yet
+        if laser.is_some_and(|l| !l.active(lifetime)) {
+            continue;
+        }
+
+        let bullet = b_coll.shape(b_trans);
+
+        // actual hits are handled by check_enemy_bullets
+        if bullet.hits(&graze) && !bullet.hits(&hitbox) {
+            commands.entity(ent).insert(Grazed);
+            result.grazes += 1;
+            result.score += GRAZE_POINTS;
+        }
+    }
+}
+
+fn score_survival(time: Res<Time>, mut result: ResMut<MissionResult>, mut carry: Local<f32>) {
+    result.survived_secs += time.delta_secs();
+
+    *carry += time.delta_secs() * SURVIVAL_POINTS_PER_SEC;
+    let points = carry.floor();
+    *carry -= points;
+    result.score += points as u64;
+}
+
+fn spawn_score_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            TouhouMarker,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Vw(20.0),
+                height: Val::Vh(10.0),
+                right: Val::Px(0.),
+                top: Val::Px(0.),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(1),
+        ))
+        .with_child((
+            Text::new(""),
+            ScoreCount,
+            TextFont {
+                font: asset_server.load("fonts/Pixelfont/slkscr.ttf"),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.5)),
+        ));
+}
+
+fn count_lives_lost(mut lost: EventReader<LifeLost>, mut result: ResMut<MissionResult>) {
+    result.lives_lost += lost.read().count() as u32;
+}
+
+fn track_ammo_left(player: Option<PlayerQ<&Ammo>>, mut result: ResMut<MissionResult>) {
+    if let Some(ammo) = player {
+        result.ammo_left = ***ammo;
+    }
+}
+
+fn update_score_hud(result: Res<MissionResult>, mut text: Query<&mut Text, With<ScoreCount>>) {
+    for mut text in &mut text {
+        **text = format!["Score: {}\nGraze: {}", result.score, result.grazes];
+    }
+}
@@ -0,0 +1,109 @@
+use super::*;
+
+/// How long the player can't be hit after the shield takes a bullet, so a
+/// single cluster doesn't drain every charge at once.
+pub const SHIELD_BREAK_INVULNERABILITY: f32 = 1.0;
+const SHIELD_BAR_COLOR: Color = Color::srgba(0.3, 0.8, 1.0, 0.9);
+
+pub fn shield_plugin(app: &mut App) {
+    app.add_systems(
+        OnEnter(GameState::Touhou),
+        spawn_shield_bar.in_set(TouhouSets::EnterTouhou),
+    )
+    .add_systems(
+        FixedUpdate,
+        (regen_shield, update_shield_bar)
+            .chain()
+            .in_set(TouhouSets::Gameplay),
+    );
+}
+
+/// Detergance plating on the player ship. Every charge soaks up one hit before
+/// [`Life`] is touched, and spent charges come back one at a time.
+#[derive(Component)]
+pub struct Shield {
+    pub charges: u32,
+    pub max: u32,
+    regen: Timer,
+}
+
+impl Shield {
+    pub fn new(max: u32, regen_secs: f32) -> Self {
+        Self {
+            charges: max,
+            max,
+            regen: Timer::from_seconds(regen_secs, TimerMode::Repeating),
+        }
+    }
+
+    /// Takes a hit if there's a charge left for it.
+    pub fn absorb(&mut self) -> bool {
+        if self.charges == 0 {
+            return false;
+        }
+
+        self.charges -= 1;
+        // getting hit starts the recharge over
+        self.regen.reset();
+        true
+    }
+
+    /// How full the shield is, counting the charge that's on its way back.
+    fn fraction(&self) -> f32 {
+        if self.max == 0 {
+            return 0.0;
+        }
+
+        let partial = if self.charges < self.max {
+            self.regen.fraction()
+        } else {
+            0.0
+        };
+
+        (self.charges as f32 + partial) / self.max as f32
+    }
+}
+
+#[derive(Component)]
+struct ShieldBar;
+
+fn spawn_shield_bar(mut commands: Commands) {
+    commands.spawn((
+        TouhouMarker,
+        ShieldBar,
+        Node {
+            width: Val::Vw(0.0),
+            height: Val::Vh(2.0),
+            left: Val::Px(0.),
+            bottom: -Val::Vh(20.),
+            ..default()
+        },
+        BackgroundColor(SHIELD_BAR_COLOR),
+        ZIndex(1),
+    ));
+}
+
+fn regen_shield(time: Res<Time>, player: Option<PlayerQ<&mut Shield>>) {
+    let Some(mut shield) = player.map(Single::into_inner) else {
+        return;
+    };
+
+    if shield.charges >= shield.max {
+        return;
+    }
+
+    shield.regen.tick(time.delta());
+
+    if shield.regen.just_finished() {
+        shield.charges += 1;
+    }
+}
+
+fn update_shield_bar(player: Option<PlayerQ<&Shield>>, mut bar: Query<&mut Node, With<ShieldBar>>) {
+    // ships without detergance just don't get a bar
+    let fraction = player.map_or(0.0, |shield| shield.fraction());
+
+    for mut node in &mut bar {
+        node.width = Val::Vw(20.0 * fraction);
+    }
+}
@@ -0,0 +1,258 @@
+use std::f32::consts::TAU;
+
+use enemy::{Health, MaxHealth};
+use items::{ItemKind, spawn_item};
+use replay::{RngStream, TouhouRng};
+
+use super::*;
+
+const MOOK_HEALTH: u32 = 30;
+// how far outside the gameplay area mooks can fly before they're gone for good
+const MOOK_MARGIN: f32 = 200.0;
+const MOOK_BAR_SIZE: Vec2 = Vec2::new(40.0, 4.0);
+
+pub fn stage_plugin(app: &mut App) {
+    app.init_resource::<Stage>()
+        .add_systems(
+            OnEnter(GameState::Touhou),
+            start_stage.in_set(TouhouSets::EnterTouhou),
+        )
+        .add_systems(
+            FixedUpdate,
+            (run_stage, move_mooks, cull_mooks, update_mook_bars)
+                .chain()
+                .in_set(TouhouSets::Gameplay),
+        )
+        .add_systems(
+            FixedPostUpdate,
+            kill_mooks
+                .after(bullet::process_enemy_hits)
+                .before(enemy_dead)
+                .in_set(TouhouSets::Gameplay),
+        );
+}
+
+/// How a mook gets across the screen, as a function of its age.
+#[derive(Clone, Copy, Debug)]
+pub enum MookPath {
+    Straight {
+        from: Vec2,
+        velocity: Vec2,
+    },
+    /// Weaves around the straight line, `frequency` times a second.
+    Sine {
+        from: Vec2,
+        velocity: Vec2,
+        amplitude: f32,
+        frequency: f32,
+    },
+}
+
+impl MookPath {
+    fn position(&self, age: f32) -> Vec2 {
+        match *self {
+            MookPath::Straight { from, velocity } => from + velocity * age,
+            MookPath::Sine {
+                from,
+                velocity,
+                amplitude,
+                frequency,
+            } => {
+                let side = velocity.normalize_or_zero().perp();
+                from + velocity * age + side * amplitude * (age * frequency * TAU).sin()
+            }
+        }
+    }
+}
+
+/// A group of mooks all taking the same path, one every `spacing` seconds.
+#[derive(Clone, Debug)]
+pub struct Wave {
+    /// seconds into the stage
+    pub at: f32,
+    pub count: usize,
+    pub spacing: f32,
+    pub path: MookPath,
+    spawned: usize,
+}
+
+impl Wave {
+    pub fn new(at: f32, count: usize, spacing: f32, path: MookPath) -> Self {
+        Self {
+            at,
+            count,
+            spacing,
+            path,
+            spawned: 0,
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.spawned >= self.count
+    }
+}
+
+/// The script for the current fight: waves of mooks, then the boss once all
+/// of them are spawned and gone.
+#[derive(Resource, Default)]
+pub struct Stage {
+    elapsed: f32,
+    waves: Vec<Wave>,
+    pub boss_spawned: bool,
+}
+
+#[derive(Component)]
+pub struct Mook {
+    path: MookPath,
+    age: f32,
+}
+
+#[derive(Component)]
+struct MookBar;
+
+fn waves(enemy: Enemies) -> Vec<Wave> {
+    let straight = |y| MookPath::Straight {
+        from: Vec2::new(1050.0, y),
+        velocity: Vec2::new(-320.0, 0.0),
+    };
+
+    let mut waves = vec![
+        Wave::new(1.0, 5, 0.4, straight(300.0)),
+        Wave::new(4.0, 5, 0.4, straight(-300.0)),
+        Wave::new(
+            8.0,
+            6,
+            0.5,
+            MookPath::Sine {
+                from: Vec2::new(1050.0, 0.0),
+                velocity: Vec2::new(-256.0, 0.0),
+                amplitude: 250.0,
+                frequency: 0.5,
+            },
+        ),
+    ];
+
+    if enemy == Enemies::MoonGirl {
+        waves.push(Wave::new(12.0, 8, 0.25, straight(400.0)));
+        waves.push(Wave::new(12.0, 8, 0.25, straight(-400.0)));
+    }
+
+    waves
+}
+
+fn start_stage(mut stage: ResMut<Stage>, params: Res<MissionParams>) {
+    *stage = Stage {
+        elapsed: 0.0,
+        // practice goes straight to the boss
+        waves: if params.practice.is_some() {
+            vec![]
+        } else {
+            waves(params.enemy)
+        },
+        boss_spawned: false,
+    };
+}
+
+fn run_stage(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<TouhouAssets>,
+    params: Res<MissionParams>,
+    mut stage: ResMut<Stage>,
+    mooks: Query<(), With<Mook>>,
+) {
+    if stage.boss_spawned {
+        return;
+    }
+
+    stage.elapsed += time.delta_secs();
+    let elapsed = stage.elapsed;
+
+    for wave in &mut stage.waves {
+        while !wave.done() && elapsed >= wave.at + wave.spacing * wave.spawned as f32 {
+            enemy::spawn_mook(&mut commands, &assets, wave.path.position(0.0), MOOK_HEALTH)
+                .insert(Mook {
+                    path: wave.path,
+                    age: 0.0,
+                })
+                .with_child((
+                    MookBar,
+                    Sprite {
+                        color: Color::srgb(1.0, 0.2, 0.2),
+                        custom_size: Some(MOOK_BAR_SIZE),
+                        ..Default::default()
+                    },
+                    Transform::from_xyz(0.0, -32.0, 0.1),
+                ));
+
+            wave.spawned += 1;
+        }
+    }
+
+    if stage.waves.iter().all(Wave::done) && mooks.is_empty() {
+        enemy::spawn_boss(&mut commands, &assets, params.enemy);
+        stage.boss_spawned = true;
+    }
+}
+
+fn move_mooks(time: Res<Time>, mut mooks: Query<(&mut Mook, &mut Transform)>) {
+    for (mut mook, mut trans) in &mut mooks {
+        mook.age += time.delta_secs();
+        trans.translation = mook.path.position(mook.age).extend(trans.translation.z);
+    }
+}
+
+fn cull_mooks(
+    mut commands: Commands,
+    area: Res<GameplayRect>,
+    mooks: Query<(Entity, &Transform), With<Mook>>,
+) {
+    let rect = area.rect.inflate(MOOK_MARGIN);
+
+    for (ent, trans) in &mooks {
+        if !rect.contains(trans.translation.xy()) {
+            commands.entity(ent).despawn_recursive();
+        }
+    }
+}
+
+fn kill_mooks(
+    mut commands: Commands,
+    assets: Res<TouhouAssets>,
+    mut rng: ResMut<TouhouRng>,
+    mooks: Query<(Entity, &Transform, &Health), With<Mook>>,
+) {
+    let rng = rng.stream(RngStream::Items);
+
+    for (ent, trans, health) in &mooks {
+        if **health > 0 {
+            continue;
+        }
+
+        // mooks are too small to hit the regular health drops, so they always
+        // leave a little something behind
+        spawn_item(
+            &mut commands,
+            rng,
+            &assets,
+            ItemKind::Ammo,
+            trans.translation.xy(),
+        );
+        commands.entity(ent).despawn_recursive();
+    }
+}
+
+fn update_mook_bars(
+    mooks: Query<(&Health, &MaxHealth, &Children), With<Mook>>,
+    mut bars: Query<&mut Sprite, With<MookBar>>,
+) {
+    for (health, max, children) in &mooks {
+        let fraction = **health as f32 / (**max).max(1) as f32;
+
+        for &child in children {
+            if let Ok(mut sprite) = bars.get_mut(child) {
+                sprite.custom_size = Some(MOOK_BAR_SIZE * Vec2::new(fraction, 1.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{asset::io::file::FileAssetReader, sprite::Anchor};
+use bullet::{BulletBundle, DelayedBullet, Target, Weapon};
+use enemy::BulletSpawner;
+use serde::Deserialize;
+
+use super::*;
+
+/// Relative to the directory Bevy finds the assets folder in, so the file is
+/// found no matter where the game is started from.
+const WEAPONS_PATH: &str = "assets/weapons.ron";
+
+pub fn weapons_plugin(app: &mut App) {
+    app.insert_resource(WeaponDefs::load());
+}
+
+/// Every player weapon, read from [`WEAPONS_PATH`] on startup. A tech can
+/// mount more than one weapon, and techs that aren't in the file don't fire
+/// anything.
+#[derive(Resource, Default, Deserialize)]
+#[serde(transparent)]
+pub struct WeaponDefs(HashMap<Tech, Vec<WeaponDef>>);
+
+impl WeaponDefs {
+    pub fn load() -> Self {
+        let path = FileAssetReader::get_base_path().join(WEAPONS_PATH);
+
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) => {
+                log::error!(
+                    "couldn't open {}, the ship has no guns: {err}",
+                    path.display()
+                );
+                return default();
+            }
+        };
+
+        ron::from_str(&text).unwrap_or_else(|err| {
+            log::error!("couldn't read {WEAPONS_PATH}, the ship has no guns: {err}");
+            default()
+        })
+    }
+
+    pub fn get(&self, tech: Tech) -> &[WeaponDef] {
+        self.0.get(&tech).map_or(&[], Vec::as_slice)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WeaponDef {
+    /// seconds between shots
+    pub interval: f32,
+    pub ammo_cost: u32,
+    pub damage: u32,
+    #[serde(default)]
+    pub salted: bool,
+    #[serde(default)]
+    pub phasing: bool,
+    /// makes it a charge weapon, see [`charge::Charge`]
+    #[serde(default)]
+    pub charge: Option<ChargeDef>,
+    pub bullet: BulletDef,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct ChargeDef {
+    /// seconds to a full charge
+    pub full_time: f32,
+    pub max_scale: f32,
+}
+
+impl WeaponDef {
+    pub fn build(&self, assets: &TouhouAssets) -> Weapon {
+        Weapon {
+            timer: Timer::new(Duration::from_secs_f32(self.interval), self.timer_mode()),
+            ammo_cost: self.ammo_cost,
+            bullet: self.bullet.build(assets),
+            salted: self.salted,
+            phasing: self.phasing,
+            damage: self.damage,
+        }
+    }
+
+    /// Charge weapons fire whenever they're let go of, so their timer has to
+    /// stay finished until then instead of wrapping around.
+    fn timer_mode(&self) -> TimerMode {
+        if self.charge.is_some() {
+            TimerMode::Once
+        } else {
+            TimerMode::Repeating
+        }
+    }
+
+    pub fn charge(&self) -> Option<charge::Charge> {
+        self.charge
+            .map(|charge| charge::Charge::new(charge.full_time, charge.max_scale))
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub enum BulletSprite {
+    Bullet,
+    Rocket,
+    Beam,
+}
+
+/// The recipe for a [`BulletSpawner`]. Every movement part is optional and
+/// they stack the same way the builder methods do.
+#[derive(Deserialize, Clone, Debug)]
+pub struct BulletDef {
+    pub sprite: BulletSprite,
+    #[serde(default)]
+    pub size: Option<Vec2>,
+    /// draw from the left edge instead of the middle, for beams
+    #[serde(default)]
+    pub anchor_left: bool,
+    /// in radians
+    #[serde(default)]
+    pub rotation: f32,
+    pub radius: f32,
+    #[serde(default)]
+    pub max_lifetime: Option<f32>,
+    /// in units/s
+    #[serde(default)]
+    pub normal: Option<Vec2>,
+    #[serde(default)]
+    pub homing: Option<HomingDef>,
+    #[serde(default)]
+    pub wave: Option<WaveDef>,
+    #[serde(default)]
+    pub laser: Option<LaserDef>,
+    /// a second stage that takes over after a while
+    #[serde(default)]
+    pub delayed: Option<Box<DelayedDef>>,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct HomingDef {
+    pub seeking_time: f32,
+    pub rotation_speed: f32,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct WaveDef {
+    pub sine_mod: f32,
+    pub velocity: Vec2,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct LaserDef {
+    pub length: f32,
+    pub width: f32,
+    #[serde(default)]
+    pub warning: f32,
+    pub duration: f32,
+    #[serde(default)]
+    pub rotation_speed: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DelayedDef {
+    pub delay: f32,
+    pub bullet: BulletDef,
+}
+
+impl BulletDef {
+    fn build(&self, assets: &TouhouAssets) -> BulletSpawner {
+        let image = match self.sprite {
+            BulletSprite::Bullet => assets.bullet1.clone(),
+            BulletSprite::Rocket => assets.rocket.clone(),
+            BulletSprite::Beam => assets.beam.clone(),
+        };
+
+        let mut spawner = BulletSpawner::new(BulletBundle {
+            transform: Transform::from_rotation(Quat::from_rotation_z(self.rotation)),
+            collider: Collider::new(self.radius),
+            sprite: Sprite {
+                image,
+                custom_size: self.size,
+                anchor: if self.anchor_left {
+                    Anchor::CenterLeft
+                } else {
+                    Anchor::Center
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        if let Some(velocity) = self.normal {
+            spawner = spawner.normal(velocity);
+        }
+        if let Some(homing) = self.homing {
+            spawner = spawner.homing(homing.seeking_time, homing.rotation_speed, Target::Enemy);
+        }
+        if let Some(wave) = self.wave {
+            spawner = spawner.wave(wave.sine_mod, wave.velocity);
+        }
+        if let Some(laser) = self.laser {
+            spawner = spawner.laser(
+                laser.length,
+                laser.width,
+                laser.warning,
+                laser.duration,
+                laser.rotation_speed,
+            );
+        }
+        if let Some(delayed) = &self.delayed {
+            spawner = spawner.delayed(DelayedBullet {
+                bullet: delayed.bullet.build(assets),
+                delay: delayed.delay,
+                deployed: false,
+            });
+        }
+        // after the laser, which sets its own lifetime
+        if let Some(max) = self.max_lifetime {
+            spawner = spawner.max_lifetime(max);
+        }
+
+        spawner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_weapons_parse() {
+        let defs: WeaponDefs = ron::from_str(include_str!("../../assets/weapons.ron")).unwrap();
+
+        let techs = [
+            Tech::HeavyBody,
+            Tech::HoverMagic,
+            Tech::MagicBullet,
+            Tech::MachineGun,
+            Tech::MachineGunT2,
+            Tech::AmmoStockpile,
+            Tech::EngineT1,
+            Tech::EngineT2,
+            Tech::Rocket,
+            Tech::ChargeRocket,
+            Tech::DeterganceT1,
+            Tech::DeterganceT2,
+            Tech::Phase,
+            Tech::Laser,
+            Tech::Familiars,
+        ];
+
+        for tech in techs {
+            if tech.category() == EquipmentCategory::Weapon {
+                assert!(!defs.get(tech).is_empty(), "{tech:?} has no weapon");
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Resource, Clone, Debug)]
 pub struct Mission {
@@ -52,7 +53,7 @@ pub enum Map {
 }
 
 #[repr(usize)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Enemies {
     RedGirl,
     Lizard,
@@ -60,16 +61,26 @@ pub enum Enemies {
     MoonGirl,
 }
 
+impl Enemies {
+    pub const ALL: [Enemies; 4] = [
+        Enemies::RedGirl,
+        Enemies::Lizard,
+        Enemies::Tentacle,
+        Enemies::MoonGirl,
+    ];
+}
+
 #[repr(usize)]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum ResourceType {
     Scientists,
     Engineer,
     MagicDust,
+    Ammunition,
 }
 
 #[repr(usize)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Tech {
     HeavyBody,
     HoverMagic,
@@ -80,15 +91,392 @@ pub enum Tech {
     EngineT1,
     EngineT2,
     Rocket,
+    ChargeRocket,
     DeterganceT1,
     DeterganceT2,
     Phase,
+    Laser,
+    Familiars,
 }
 
-#[derive(Resource)]
+/// What kind of ship part a tech is, which decides the slots it fits in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EquipmentCategory {
+    Weapon,
+    Engine,
+    Core,
+    Shield,
+    Utility,
+}
+
+impl EquipmentCategory {
+    pub fn label(self) -> &'static str {
+        match self {
+            EquipmentCategory::Weapon => "weapon",
+            EquipmentCategory::Engine => "engine",
+            EquipmentCategory::Core => "core",
+            EquipmentCategory::Shield => "shield",
+            EquipmentCategory::Utility => "utility",
+        }
+    }
+}
+
+impl Tech {
+    /// How equipping the tech changes the ship. Weapons and bullet effects
+    /// depend on the slot group they're in, so they're handled on the touhou
+    /// side and don't show up here.
+    pub fn modifiers(self) -> &'static [Modifier] {
+        match self {
+            Tech::AmmoStockpile => &[
+                Modifier::Add(Stat::Ammo, 1000.0),
+                Modifier::Add(Stat::Bombs, 1.0),
+            ],
+            Tech::HeavyBody => &[
+                Modifier::Mul(Stat::Ammo, 1.5),
+                Modifier::Add(Stat::Lives, 3.0),
+                Modifier::Add(Stat::Hitbox, 5.0),
+            ],
+            Tech::DeterganceT1 => &[
+                Modifier::Add(Stat::Shield, 1.0),
+                Modifier::Min(Stat::ShieldRegen, 8.0),
+            ],
+            Tech::DeterganceT2 => &[
+                Modifier::Add(Stat::Shield, 2.0),
+                Modifier::Min(Stat::ShieldRegen, 5.0),
+            ],
+            Tech::EngineT1 => &[Modifier::Mul(Stat::Speed, 2.0)],
+            Tech::EngineT2 => &[
+                Modifier::Mul(Stat::Speed, 4.0),
+                Modifier::Mul(Stat::Damage, 1.5),
+            ],
+            _ => &[],
+        }
+    }
+
+    pub fn category(self) -> EquipmentCategory {
+        match self {
+            Tech::MachineGun
+            | Tech::MachineGunT2
+            | Tech::Rocket
+            | Tech::ChargeRocket
+            | Tech::Laser
+            | Tech::Familiars => EquipmentCategory::Weapon,
+            Tech::EngineT1 | Tech::EngineT2 | Tech::HoverMagic => EquipmentCategory::Engine,
+            Tech::HeavyBody => EquipmentCategory::Core,
+            Tech::DeterganceT1 | Tech::DeterganceT2 => EquipmentCategory::Shield,
+            Tech::AmmoStockpile | Tech::MagicBullet | Tech::Phase => EquipmentCategory::Utility,
+        }
+    }
+}
+
+/// What happened in the last touhou fight, kept up to date while it's ongoing
+/// and read back by the strategy layer once the mission is over.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct MissionResult {
+    pub score: u64,
+    pub grazes: u32,
+    pub damage_dealt: u32,
+    pub spellcards_captured: u32,
+    pub survived_secs: f32,
+    pub magic_dust: usize,
+    /// whether the card of a practice run was captured
+    pub practice_captured: bool,
+    /// what the craft's hull takes once it's back in the hangar
+    pub lives_lost: u32,
+    /// rounds taken from stock at launch
+    pub ammo_loaded: u32,
+    /// rounds still on board, these go back into stock
+    pub ammo_left: u32,
+}
+
+/// How hard the campaign is, picked when it starts. Fights are tuned for
+/// `Normal` and scaled from there.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Lunatic,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Lunatic,
+    ];
+
+    /// Multiplier on how long emitters wait between volleys.
+    pub fn fire_interval(self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.4,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+            Difficulty::Lunatic => 0.6,
+        }
+    }
+
+    /// Multiplier on how many bullets go out per volley.
+    pub fn bullet_count(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.6,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Lunatic => 1.5,
+        }
+    }
+
+    pub fn bullet_speed(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.15,
+            Difficulty::Lunatic => 1.3,
+        }
+    }
+
+    pub fn enemy_health(self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.7,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.3,
+            Difficulty::Lunatic => 1.6,
+        }
+    }
+
+    /// How long a new mission stays on the map before the invader gets its way.
+    pub fn mission_time(self) -> isize {
+        match self {
+            Difficulty::Easy => 14,
+            Difficulty::Normal => 10,
+            Difficulty::Hard => 8,
+            Difficulty::Lunatic => 6,
+        }
+    }
+
+    /// Scientists lost whenever a mission goes badly.
+    pub fn scientist_penalty(self) -> usize {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Normal | Difficulty::Hard => 2,
+            Difficulty::Lunatic => 3,
+        }
+    }
+}
+
+/// Something a pilot picked up over their career, it changes how the ship
+/// handles in a fight.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Perk {
+    /// a smaller hitbox
+    SlimProfile,
+    /// stays untouchable for longer after a hit
+    CoolHead,
+    /// moves faster while focused
+    SteadyHands,
+}
+
+impl Perk {
+    pub const ALL: [Perk; 3] = [Perk::SlimProfile, Perk::CoolHead, Perk::SteadyHands];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Perk::SlimProfile => "Slim profile",
+            Perk::CoolHead => "Cool head",
+            Perk::SteadyHands => "Steady hands",
+        }
+    }
+
+    pub fn modifiers(self) -> &'static [Modifier] {
+        match self {
+            Perk::SlimProfile => &[Modifier::Add(Stat::Hitbox, -2.0)],
+            // these change how the ship handles rather than its numbers
+            Perk::CoolHead | Perk::SteadyHands => &[],
+        }
+    }
+}
+
+/// A number on the ship that techs and perks can change, see [`Modifier`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Stat {
+    Speed,
+    Damage,
+    Ammo,
+    Hitbox,
+    Lives,
+    Bombs,
+    Shield,
+    ShieldRegen,
+}
+
+/// One change to a [`Stat`]. Everything added goes onto the base value first,
+/// the multipliers come after and the caps last, so the order parts are
+/// equipped in doesn't matter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Modifier {
+    Add(Stat, f32),
+    Mul(Stat, f32),
+    /// for stats where only the best part equipped counts
+    Min(Stat, f32),
+}
+
+/// What the player ship goes into a fight with, once the loadout and pilot
+/// have had their say.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShipStats {
+    /// in units/s
+    pub speed: f32,
+    /// multiplies the damage of every gun
+    pub damage: f32,
+    /// how many rounds fit, what's actually loaded depends on the stock
+    pub ammo: f32,
+    /// the hitbox radius
+    pub hitbox: f32,
+    pub lives: f32,
+    pub bombs: f32,
+    /// hits the detergance plating soaks up before any lives are lost
+    pub shield: f32,
+    /// seconds for a spent shield charge to come back, the fastest plating
+    /// equipped sets it
+    pub shield_regen: f32,
+}
+
+impl ShipStats {
+    pub const BASE: ShipStats = ShipStats {
+        speed: 416.0,
+        damage: 1.0,
+        ammo: 1000.0,
+        hitbox: 7.5,
+        lives: 3.0,
+        bombs: 2.0,
+        shield: 0.0,
+        shield_regen: f32::INFINITY,
+    };
+    /// however slim the pilot, something has to be left to hit
+    const MIN_HITBOX: f32 = 1.0;
+
+    /// The stats of a craft with `hull` lives left, carrying `techs` and flown
+    /// by a pilot with `perks`.
+    pub fn for_ship(hull: usize, techs: impl IntoIterator<Item = Tech>, perks: &[Perk]) -> Self {
+        let modifiers: Vec<Modifier> = techs
+            .into_iter()
+            .flat_map(|tech| tech.modifiers())
+            .chain(perks.iter().flat_map(|perk| perk.modifiers()))
+            .copied()
+            .collect();
+
+        let mut stats = ShipStats {
+            lives: hull as f32,
+            ..Self::BASE
+        };
+
+        for modifier in &modifiers {
+            if let Modifier::Add(stat, by) = *modifier {
+                *stats.get_mut(stat) += by;
+            }
+        }
+        for modifier in &modifiers {
+            if let Modifier::Mul(stat, by) = *modifier {
+                *stats.get_mut(stat) *= by;
+            }
+        }
+        for modifier in &modifiers {
+            if let Modifier::Min(stat, cap) = *modifier {
+                let value = stats.get_mut(stat);
+                *value = value.min(cap);
+            }
+        }
+
+        stats.hitbox = stats.hitbox.max(Self::MIN_HITBOX);
+        stats
+    }
+
+    fn get_mut(&mut self, stat: Stat) -> &mut f32 {
+        match stat {
+            Stat::Speed => &mut self.speed,
+            Stat::Damage => &mut self.damage,
+            Stat::Ammo => &mut self.ammo,
+            Stat::Hitbox => &mut self.hitbox,
+            Stat::Lives => &mut self.lives,
+            Stat::Bombs => &mut self.bombs,
+            Stat::Shield => &mut self.shield,
+            Stat::ShieldRegen => &mut self.shield_regen,
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
 pub struct MissionParams {
     pub loadout: Vec<(Tech, bool)>,
     pub enemy: Enemies,
     pub map: Map,
+    pub difficulty: Difficulty,
+    /// set for spellcard practice, the index of the card to fight in
+    /// [`crate::touhou::spellcards`]
+    pub practice: Option<usize>,
+    /// lives the player starts with before equipment, from the craft's hull
+    pub hull: usize,
+    /// rounds in stock, the craft loads as many of them as it can carry
+    pub ammo: u32,
+    /// whatever the pilot flying the fight has learned
+    pub perks: Vec<Perk>,
     //    pub mission: Mission,
 }
+
+/// Everything needed to play a touhou fight back exactly: the rng seed, the
+/// mission setup and the input bits for every fixed update tick.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub seed: u64,
+    /// fixed update rate the inputs were recorded at
+    pub hz: f64,
+    pub params: MissionParams,
+    pub inputs: Vec<u8>,
+    pub score: u64,
+}
+
+#[derive(Resource, Default)]
+pub struct Replays {
+    pub recorded: Vec<Replay>,
+    /// set by the replay browser to watch a fight instead of playing a new one
+    pub playback: Option<Replay>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn additions_come_before_multipliers() {
+        let stats = ShipStats::for_ship(3, [Tech::HeavyBody, Tech::AmmoStockpile], &[]);
+        let reversed = ShipStats::for_ship(3, [Tech::AmmoStockpile, Tech::HeavyBody], &[]);
+
+        assert_eq!(stats.ammo, (ShipStats::BASE.ammo + 1000.0) * 1.5);
+        assert_eq!(stats, reversed);
+    }
+
+    #[test]
+    fn lives_start_from_the_hull() {
+        assert_eq!(ShipStats::for_ship(1, [], &[]).lives, 1.0);
+        assert_eq!(ShipStats::for_ship(5, [Tech::HeavyBody], &[]).lives, 8.0);
+    }
+
+    #[test]
+    fn shield_recharges_at_the_fastest_platings_rate() {
+        let stats = ShipStats::for_ship(3, [Tech::DeterganceT2, Tech::DeterganceT1], &[]);
+        let doubled = ShipStats::for_ship(3, [Tech::DeterganceT2, Tech::DeterganceT2], &[]);
+
+        assert_eq!(stats.shield, 3.0);
+        assert_eq!(stats.shield_regen, 5.0);
+        assert_eq!(doubled.shield_regen, 5.0);
+    }
+
+    #[test]
+    fn hitbox_never_shrinks_below_the_minimum() {
+        let stats = ShipStats::for_ship(3, [], &[Perk::SlimProfile; 4]);
+
+        assert_eq!(stats.hitbox, ShipStats::MIN_HITBOX);
+    }
+}
@@ -1,15 +1,23 @@
 use crate::prelude::*;
 use rand::prelude::*;
+use bevy::ecs::system::SystemParam;
 use std::collections::HashMap;
 use std::time::Duration;
 use ResourceType::*;
 
+mod hangar;
+mod pilots;
+mod presets;
 mod uispawner;
 
+use hangar::*;
+use pilots::*;
+use presets::*;
 use uispawner::*;
 
 pub fn xcom_plugin(app: &mut App) {
-    app.add_systems(Startup, setup)
+    app.add_plugins((presets_plugin, hangar_plugin, pilots_plugin))
+        .add_systems(Startup, setup)
         .add_systems(OnEnter(GameState::Xcom), on_xcom)
         .add_systems(
             PreUpdate,
@@ -43,7 +51,13 @@ pub fn xcom_plugin(app: &mut App) {
         )
         .add_systems(
             Update,
-            (unequip_loadout, equip_loadout)
+            (
+                unequip_loadout,
+                equip_loadout,
+                drag_equipment,
+                drop_equipment,
+                update_stat_summary,
+            )
                 .run_if(in_state(GameState::Xcom).and(in_state(Focus::Mission))),
         )
         .add_systems(OnExit(GameState::Xcom), off_xcom)
@@ -56,8 +70,29 @@ pub fn xcom_plugin(app: &mut App) {
         .add_systems(OnExit(Focus::Mission), off_mission)
         .add_systems(OnEnter(Focus::Notice), on_notice)
         .add_systems(OnExit(Focus::Notice), off_notice)
+        .add_systems(OnEnter(Focus::Replays), on_replays)
+        .add_systems(OnExit(Focus::Replays), off_replays)
+        .add_systems(OnEnter(Focus::Controls), on_controls)
+        .add_systems(OnExit(Focus::Controls), off_controls)
+        .add_systems(OnEnter(Focus::Difficulty), on_difficulty)
+        .add_systems(OnExit(Focus::Difficulty), off_difficulty)
+        .add_systems(OnEnter(Focus::Practice), on_practice)
+        .add_systems(OnExit(Focus::Practice), off_practice)
+        .add_systems(
+            Update,
+            update_practice_map_label
+                .run_if(in_state(GameState::Xcom).and(in_state(Focus::Practice))),
+        )
+        .init_resource::<Rebinding>()
+        .add_systems(
+            Update,
+            (capture_rebind, update_binding_labels)
+                .chain()
+                .run_if(in_state(GameState::Xcom).and(in_state(Focus::Controls))),
+        )
         .add_systems(OnEnter(touhou::MissionState::Fail), failed_mission)
         .add_systems(OnEnter(touhou::MissionState::Success), suceeded_mission)
+        .add_systems(OnEnter(touhou::MissionState::Retreat), retreated_mission)
         .add_event::<XcomTick>()
         .add_event::<MissionCreated>()
         .add_event::<ScienceChanged>();
@@ -74,6 +109,30 @@ pub struct MissionScreen;
 #[derive(Component)]
 pub struct NoticeScreen;
 
+#[derive(Component)]
+pub struct ReplayScreen;
+
+#[derive(Component)]
+pub struct ControlsScreen;
+
+#[derive(Component)]
+pub struct DifficultyScreen;
+
+#[derive(Component)]
+pub struct PracticeScreen;
+
+/// Text on the practice screen's map button.
+#[derive(Component)]
+pub struct PracticeMapLabel;
+
+/// Text showing what an action is currently bound to.
+#[derive(Component)]
+pub struct BindingLabel(pub Action);
+
+/// The action waiting for a key or gamepad button on the controls screen.
+#[derive(Resource, Default)]
+pub struct Rebinding(Option<Action>);
+
 #[derive(Component)]
 pub struct CurrentResearch;
 
@@ -89,18 +148,83 @@ pub struct TitleNode;
 #[derive(Component, Clone)]
 pub struct YappNode;
 
+/// How a fight went, and whether it was a real mission in the first place.
+#[derive(SystemParam)]
+pub struct FightOutcome<'w> {
+    result: Res<'w, MissionResult>,
+    params: Res<'w, MissionParams>,
+    practice: ResMut<'w, PracticeStats>,
+    replays: ResMut<'w, Replays>,
+}
+
+impl FightOutcome<'_> {
+    /// Wraps up replays and practice runs, which leave the campaign alone.
+    /// Returns false for a real mission, that's up to the caller.
+    fn end_off_campaign<'a>(
+        &mut self,
+        context: &mut XcomState,
+        marker_nodes: impl IntoIterator<Item = Mut<'a, Node>>,
+    ) -> bool {
+        if self.replays.playback.take().is_some() {
+            end_playback(context, &self.result, marker_nodes);
+            return true;
+        }
+
+        if let Some(card) = self.params.practice {
+            end_practice(
+                context,
+                &self.params,
+                &self.result,
+                &mut self.practice,
+                card,
+                marker_nodes,
+            );
+            return true;
+        }
+
+        false
+    }
+}
+
 pub fn failed_mission(
     mut context: ResMut<XcomState>,
+    mut outcome: FightOutcome,
     mut next_state: ResMut<NextState<Focus>>,
     mut next_scene: ResMut<NextState<GameState>>,
     mut image_query: Query<&mut Node, With<MissionMarker>>,
 ) {
+    if outcome.end_off_campaign(&mut context, &mut image_query) {
+        next_scene.set(GameState::Xcom);
+        next_state.set(Focus::Notice);
+        return;
+    }
+    let FightOutcome { result, .. } = outcome;
+
+    let penalty = context.difficulty.scientist_penalty();
+    let lost = context.lose_craft();
+    // whatever was on board went down with it
+    settle_ammo(&mut context, result.ammo_loaded, 0);
+    let crew = match lost.pilot {
+        Some(pilot) => format![
+            " {} didn't make it out, {} joins as a rookie.",
+            pilot.name,
+            context.hire_pilot()
+        ],
+        None => String::new(),
+    };
     context.notice_title = "Mission Failed".to_string();
-    context.notice_text = "The battle is lost. You have lost the craft and the enemy won their mission. Lost 2 scientist in budget concerns,".to_string();
+    context.total_score += result.score;
+    context.notice_text = format!(
+        "The battle is lost. The {} went down and the enemy won their mission, a replacement is on order.{} Lost {} scientist in budget concerns,\n{}",
+        lost.name,
+        crew,
+        penalty,
+        result_text(&result, context.total_score)
+    );
 
     let scientist: &mut usize = &mut context.inventory.get_mut(&Scientists).unwrap().amount;
-    if (*scientist > 2) {
-        *scientist -= 2;
+    if (*scientist > penalty) {
+        *scientist -= penalty;
     } else {
         game_over();
     }
@@ -115,15 +239,35 @@ pub fn failed_mission(
 
 pub fn suceeded_mission(
     mut context: ResMut<XcomState>,
+    mut outcome: FightOutcome,
     mut next_state: ResMut<NextState<Focus>>,
     mut commands: Commands,
     mut next_scene: ResMut<NextState<GameState>>,
     mut image_query: Query<Entity, With<MissionMarker>>,
+    mut marker_nodes: Query<&mut Node, With<MissionMarker>>,
 ) {
+    if outcome.end_off_campaign(&mut context, &mut marker_nodes) {
+        next_scene.set(GameState::Xcom);
+        next_state.set(Focus::Notice);
+        return;
+    }
+    let FightOutcome { result, .. } = outcome;
+
+    context.craft_mut().take_damage(result.lives_lost);
+    settle_ammo(&mut context, result.ammo_loaded, result.ammo_left);
+    let debrief = context.debrief_pilot(&result);
     context.notice_title = "Sucessfull mission".to_string();
-    context.notice_text = "The enemy yields. The magical loot will greatly increase our research efforts. Got 2 scientist".to_string();
+    context.total_score += result.score;
+    context.notice_text = format!(
+        "The enemy yields. The magical loot will greatly increase our research efforts. Got 2 scientist and {} magic dust\n{}\n{}\n{}",
+        result.magic_dust,
+        context.craft().status(context.time),
+        debrief,
+        result_text(&result, context.total_score)
+    );
     let scientist: &mut usize = &mut context.inventory.get_mut(&Scientists).unwrap().amount;
     *scientist += 2;
+    context.inventory.get_mut(&MagicDust).unwrap().amount += result.magic_dust;
 
     for mut marker_node in &mut image_query {
         commands.entity(marker_node).despawn_recursive();
@@ -133,6 +277,108 @@ pub fn suceeded_mission(
     next_state.set(Focus::Notice);
 }
 
+/// Watching a replay doesn't count as a mission, so nothing is won or lost.
+fn end_playback<'a>(
+    context: &mut XcomState,
+    result: &MissionResult,
+    marker_nodes: impl IntoIterator<Item = Mut<'a, Node>>,
+) {
+    context.notice_title = "Replay finished".to_string();
+    context.notice_text = format!(
+        "Score: {} Graze: {} Spellcards: {}",
+        result.score, result.grazes, result.spellcards_captured
+    );
+
+    for mut marker_node in marker_nodes {
+        marker_node.display = Display::Flex;
+    }
+}
+
+/// Practice runs don't touch the campaign either, they only count towards
+/// the card's stats.
+fn end_practice<'a>(
+    context: &mut XcomState,
+    params: &MissionParams,
+    result: &MissionResult,
+    practice: &mut PracticeStats,
+    card: usize,
+    marker_nodes: impl IntoIterator<Item = Mut<'a, Node>>,
+) {
+    practice.record(params.enemy, card, result.practice_captured);
+    practice.save();
+
+    let stats = practice.card(params.enemy, card);
+    context.notice_title = if result.practice_captured {
+        "Spellcard captured".to_string()
+    } else {
+        "Practice over".to_string()
+    };
+    context.notice_text = format!(
+        "{}\nCaptured {} of {} attempts\nScore: {} Graze: {}",
+        touhou::spellcards(params.enemy)[card].name,
+        stats.captures,
+        stats.attempts,
+        result.score,
+        result.grazes
+    );
+
+    for mut marker_node in marker_nodes {
+        marker_node.display = Display::Flex;
+    }
+}
+
+/// How much closer to running out a mission gets when the craft retreats from it.
+const RETREAT_TIME_PENALTY: isize = 3;
+
+pub fn retreated_mission(
+    mut context: ResMut<XcomState>,
+    mut outcome: FightOutcome,
+    mut next_state: ResMut<NextState<Focus>>,
+    mut next_scene: ResMut<NextState<GameState>>,
+    mut markers: Query<(&mut Node, &mut MissionMarker)>,
+) {
+    next_scene.set(GameState::Xcom);
+    next_state.set(Focus::Notice);
+
+    if outcome.end_off_campaign(&mut context, markers.iter_mut().map(|(node, _)| node)) {
+        return;
+    }
+    let FightOutcome { result, .. } = outcome;
+
+    if let Some(Ok((_, mut marker))) = context.selected_mission.map(|ent| markers.get_mut(ent)) {
+        marker.0.time_left -= RETREAT_TIME_PENALTY;
+    }
+
+    context.craft_mut().take_damage(result.lives_lost);
+    settle_ammo(&mut context, result.ammo_loaded, result.ammo_left);
+    let debrief = context.debrief_pilot(&result);
+    context.notice_title = "Retreat".to_string();
+    context.notice_text = format!(
+        "The craft made it home, but the invader is still out there and has had time to dig in. The mission remains open with less time left.\n{}\n{}\n{}",
+        context.craft().status(context.time),
+        debrief,
+        result_text(&result, context.total_score)
+    );
+
+    for (mut marker_node, _) in &mut markers {
+        marker_node.display = Display::Flex;
+    }
+}
+
+/// Takes what the craft was loaded with out of stock and puts back what it
+/// brought home, pickups included.
+fn settle_ammo(context: &mut XcomState, loaded: u32, returned: u32) {
+    let stock = &mut context.inventory.get_mut(&Ammunition).unwrap().amount;
+    *stock = stock.saturating_sub(loaded as usize) + returned as usize;
+}
+
+fn result_text(result: &MissionResult, total_score: u64) -> String {
+    format!(
+        "Score: {} Graze: {} Spellcards: {}\nTotal score: {}",
+        result.score, result.grazes, result.spellcards_captured, total_score
+    )
+}
+
 pub fn on_science(
     mut science_query: Query<&mut Node, With<ScienceScreen>>,
     mut current_research_text: Query<&mut Text, (With<CurrentResearch>, Without<ScientistDisplay>)>,
@@ -203,6 +449,107 @@ pub fn off_notice(mut notice_query: Query<&mut Node, With<NoticeScreen>>) {
     }
 }
 
+pub fn on_controls(mut controls_query: Query<&mut Node, With<ControlsScreen>>) {
+    for mut controls_node in &mut controls_query {
+        controls_node.display = Display::Flex;
+    }
+}
+
+pub fn off_controls(
+    mut controls_query: Query<&mut Node, With<ControlsScreen>>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    rebinding.0 = None;
+
+    for mut controls_node in &mut controls_query {
+        controls_node.display = Display::None;
+    }
+}
+
+fn capture_rebind(
+    mut rebinding: ResMut<Rebinding>,
+    mut settings: ResMut<Settings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+) {
+    let Some(action) = rebinding.0 else {
+        return;
+    };
+
+    if let Some(key) = keyboard.get_just_pressed().next() {
+        settings.controls.rebind_key(action, *key);
+    } else if let Some(button) = gamepads
+        .iter()
+        .find_map(|pad| pad.get_just_pressed().next().copied())
+    {
+        settings.controls.rebind_button(action, button);
+    } else {
+        return;
+    }
+
+    rebinding.0 = None;
+    settings.save();
+}
+
+fn update_binding_labels(
+    settings: Res<Settings>,
+    rebinding: Res<Rebinding>,
+    mut labels: Query<(&mut Text, &BindingLabel)>,
+) {
+    for (mut text, label) in &mut labels {
+        **text = if rebinding.0 == Some(label.0) {
+            format!["{}: press a key or button", label.0.label()]
+        } else {
+            format!["{}: {}", label.0.label(), settings.controls.describe(label.0)]
+        };
+    }
+}
+
+pub fn on_difficulty(mut difficulty_query: Query<&mut Node, With<DifficultyScreen>>) {
+    for mut difficulty_node in &mut difficulty_query {
+        difficulty_node.display = Display::Flex;
+    }
+}
+
+pub fn off_difficulty(mut difficulty_query: Query<&mut Node, With<DifficultyScreen>>) {
+    for mut difficulty_node in &mut difficulty_query {
+        difficulty_node.display = Display::None;
+    }
+}
+
+pub fn on_practice(mut practice_query: Query<&mut Node, With<PracticeScreen>>) {
+    for mut practice_node in &mut practice_query {
+        practice_node.display = Display::Flex;
+    }
+}
+
+pub fn off_practice(mut practice_query: Query<&mut Node, With<PracticeScreen>>) {
+    for mut practice_node in &mut practice_query {
+        practice_node.display = Display::None;
+    }
+}
+
+fn update_practice_map_label(
+    context: Res<XcomState>,
+    mut labels: Query<&mut Text, With<PracticeMapLabel>>,
+) {
+    for mut text in &mut labels {
+        **text = format!["Map: {:?}", context.practice_map];
+    }
+}
+
+pub fn on_replays(mut replay_query: Query<&mut Node, With<ReplayScreen>>) {
+    for mut replay_node in &mut replay_query {
+        replay_node.display = Display::Flex;
+    }
+}
+
+pub fn off_replays(mut replay_query: Query<&mut Node, With<ReplayScreen>>) {
+    for mut replay_node in &mut replay_query {
+        replay_node.display = Display::None;
+    }
+}
+
 pub fn on_mission(
     mut mission_query: Query<&mut Node, With<MissionScreen>>,
     mission: ResMut<MissionParams>,
@@ -219,6 +566,10 @@ pub fn on_mission(
             Enemies::Tentacle => "Tentacle monster".to_string(),
             Enemies::MoonGirl => "Moon princes".to_string(),
         };
+
+        if let Some(card) = mission.practice {
+            **text += &format!["\n{}", touhou::spellcards(mission.enemy)[card].name];
+        }
     }
 }
 
@@ -274,11 +625,28 @@ pub struct XcomState {
     pub assets: XcomResources,
     pub active_missions: Vec<Mission>,
     pub finished_missions: Vec<Mission>,
-    pub loadout: HashMap<Slot, Option<Tech>>,
+    pub hangar: Vec<Craft>,
+    /// index into `hangar`
+    pub selected_craft: usize,
+    /// how many craft have been named so far, see [`XcomState::lose_craft`]
+    pub crafts_built: usize,
+    /// saved loadouts, these last as long as the running campaign does
+    pub presets: Vec<LoadoutPreset>,
+    /// pilots waiting in reserve, the rest are sitting in a craft
+    pub pilots: Vec<Pilot>,
+    /// how many pilots have been named so far, see [`XcomState::hire_pilot`]
+    pub pilots_hired: usize,
     pub timer: Timer,
     pub speed: usize,
     pub notice_title: String,
     pub notice_text: String,
+    pub total_score: u64,
+    /// picked on the first visit to the map, copied into every mission
+    pub difficulty: Difficulty,
+    /// what the practice screen will fight on
+    pub practice_map: Map,
+    /// the mission marker the current loadout screen / fight belongs to
+    pub selected_mission: Option<Entity>,
 }
 
 #[repr(usize)]
@@ -290,16 +658,69 @@ pub enum ButtonPath {
     MissionMenu,
     StartMission,
     StartResearch,
+    ReplayMenu,
+    WatchReplay,
+    ControlsMenu,
+    Rebind,
+    SelectDifficulty,
+    PracticeMenu,
+    PracticeCard,
+    PracticeMap,
+    SavePreset,
+    ApplyPreset,
+    DeletePreset,
+    SelectCraft,
+    AssignPilot,
 }
 
 #[repr(usize)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Slot {
     Front,
     Core1,
     Engine,
     LeftWing1,
     RightWing1,
+    Shield,
+}
+
+impl Slot {
+    /// In the order clicking a piece of equipment tries to fill them.
+    pub const ALL: [Slot; 6] = [
+        Slot::Front,
+        Slot::LeftWing1,
+        Slot::RightWing1,
+        Slot::Core1,
+        Slot::Engine,
+        Slot::Shield,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Slot::Front => "front",
+            Slot::Core1 => "core",
+            Slot::Engine => "engine",
+            Slot::LeftWing1 => "left wing",
+            Slot::RightWing1 => "right wing",
+            Slot::Shield => "shield",
+        }
+    }
+
+    pub fn accepts(self) -> &'static [EquipmentCategory] {
+        use EquipmentCategory::*;
+
+        match self {
+            Slot::Front | Slot::LeftWing1 | Slot::RightWing1 => &[Weapon, Utility],
+            // the core mount also takes a gun, fired with focus held
+            Slot::Core1 => &[Core, Weapon],
+            Slot::Engine => &[Engine],
+            Slot::Shield => &[Shield],
+        }
+    }
+
+    pub fn fits(self, tech: Tech) -> bool {
+        self.accepts().contains(&tech.category())
+    }
 }
 
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
@@ -310,6 +731,10 @@ pub enum Focus {
     Production,
     Mission,
     Notice, //Doesnt *do* anything but tells a lot
+    Replays,
+    Controls,
+    Difficulty,
+    Practice,
 }
 
 #[derive(Component)]
@@ -321,32 +746,93 @@ struct BackDropFade;
 #[derive(Component)]
 struct LoadoutIcon;
 
+/// Tells the player why a piece of equipment didn't go where they put it.
+#[derive(Component)]
+pub struct LoadoutFeedback;
+
+/// The icon following the cursor while a piece of equipment is dragged.
+#[derive(Component)]
+pub struct DragGhost;
+
+/// What the selected craft would launch with, see [`ShipStats`].
+#[derive(Component)]
+pub struct StatSummary;
+
 #[derive(Component, Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub struct ScienceSelect(pub Tech);
 
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ReplaySelect(pub usize);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct RebindSelect(pub Action);
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DifficultySelect(pub Difficulty);
+
+/// A boss and the index of one of its spellcards.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PracticeSelect(pub Enemies, pub usize);
+
+/// Index into [`XcomState::presets`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct PresetSelect(pub usize);
+
 #[derive(Component, Debug)]
 pub struct MissionMarker(Mission);
 
 #[derive(Component, Debug)]
 pub struct MissionPrompt;
 
+/// Where a button press takes the player, within the strategy layer or out of it.
+#[derive(SystemParam)]
+struct NextScreen<'w> {
+    focus: ResMut<'w, NextState<Focus>>,
+    scene: ResMut<'w, NextState<GameState>>,
+}
+
 fn button_system(
     mut interaction_query: Query<
         (
+            Entity,
             &Interaction,
             &mut ImageNode,
             &ButtonLink,
             Option<&ScienceSelect>,
             Option<&MissionMarker>,
+            Option<&ReplaySelect>,
+            Option<&RebindSelect>,
+            Option<&DifficultySelect>,
+            Option<&PracticeSelect>,
+            Option<&PresetSelect>,
         ),
         (Changed<Interaction>, With<Button>),
     >,
     mut context: ResMut<XcomState>,
     mut mission_params: ResMut<MissionParams>,
-    mut next_state: ResMut<NextState<Focus>>,
-    mut next_scene: ResMut<NextState<GameState>>,
+    mut replays: ResMut<Replays>,
+    mut rebinding: ResMut<Rebinding>,
+    screen: NextScreen,
+    mut feedback: Query<&mut Text, With<LoadoutFeedback>>,
 ) {
-    for (interaction, mut sprite, link, potential_tech, potential_mission) in &mut interaction_query
+    let NextScreen {
+        focus: mut next_state,
+        scene: mut next_scene,
+    } = screen;
+
+    for (
+        ent,
+        interaction,
+        mut sprite,
+        link,
+        potential_tech,
+        potential_mission,
+        potential_replay,
+        potential_rebind,
+        potential_difficulty,
+        potential_practice,
+        potential_preset,
+    ) in &mut interaction_query
     {
         match *interaction {
             Interaction::Pressed => {
@@ -367,6 +853,7 @@ fn button_system(
                     }
                     ButtonPath::MissionMenu => {
                         next_state.set(Focus::Mission);
+                        context.selected_mission = Some(ent);
                         log::info!("setting mission params, mission: {:#?}", potential_mission);
 
                         *mission_params = MissionParams {
@@ -377,14 +864,60 @@ fn button_system(
                                 15.0..=23.0 => Map::Dusk,
                                 _ => Map::Night,
                             },
+                            difficulty: context.difficulty,
+                            practice: None,
+                            hull: MAX_HULL,
+                            ammo: u32::MAX,
+                            perks: vec![],
                         };
                     }
 
                     ButtonPath::StartMission => {
                         log::info!("Starting a Mission! {:#?}", potential_mission);
+
+                        // practice is flown in the simulator, any craft will do
+                        if mission_params.practice.is_none() {
+                            if !context.craft().ready(context.time) {
+                                for mut text in &mut feedback {
+                                    **text = format![
+                                        "{} hasn't been delivered yet",
+                                        context.craft().name
+                                    ];
+                                }
+                                continue;
+                            }
+                            if context.craft().pilot.is_none() {
+                                for mut text in &mut feedback {
+                                    **text = format![
+                                        "{} has nobody to fly it",
+                                        context.craft().name
+                                    ];
+                                }
+                                continue;
+                            }
+                            if context.inventory[&Ammunition].amount == 0 {
+                                for mut text in &mut feedback {
+                                    **text = "There's no ammunition left to load".to_string();
+                                }
+                                continue;
+                            }
+                            mission_params.hull = context.craft().hull;
+                            mission_params.ammo = context.inventory[&Ammunition]
+                                .amount
+                                .try_into()
+                                .unwrap_or(u32::MAX);
+                        }
+
+                        // the simulator still goes by how the pilot flies
+                        mission_params.perks = context
+                            .craft()
+                            .pilot
+                            .as_ref()
+                            .map_or(vec![], |pilot| pilot.perks.clone());
+
                         let mut loadout = vec![];
 
-                        for (key, value) in &context.loadout {
+                        for (key, value) in &context.craft().loadout {
                             if let Some(value) = value {
                                 loadout.push((
                                     *value,
@@ -417,6 +950,101 @@ fn button_system(
                             next_state.set(Focus::Notice);
                         }
                     }
+
+                    ButtonPath::ReplayMenu => {
+                        next_state.set(Focus::Replays);
+                    }
+
+                    ButtonPath::WatchReplay => {
+                        if let Some(replay) =
+                            potential_replay.and_then(|select| replays.recorded.get(select.0))
+                        {
+                            *mission_params = replay.params.clone();
+                            replays.playback = Some(replay.clone());
+                            next_scene.set(GameState::Touhou);
+                        }
+                    }
+
+                    ButtonPath::ControlsMenu => {
+                        next_state.set(Focus::Controls);
+                    }
+
+                    ButtonPath::Rebind => {
+                        rebinding.0 = potential_rebind.map(|select| select.0);
+                    }
+
+                    ButtonPath::PracticeMenu => {
+                        next_state.set(Focus::Practice);
+                    }
+
+                    ButtonPath::PracticeMap => {
+                        context.practice_map = match context.practice_map {
+                            Map::Day => Map::Dusk,
+                            Map::Dusk => Map::Night,
+                            Map::Night => Map::Moon,
+                            Map::Moon => Map::Day,
+                        };
+                    }
+
+                    // the loadout is picked on the regular mission screen
+                    ButtonPath::PracticeCard => {
+                        if let Some(&PracticeSelect(enemy, card)) = potential_practice {
+                            context.selected_mission = None;
+                            *mission_params = MissionParams {
+                                loadout: vec![],
+                                enemy,
+                                map: context.practice_map,
+                                difficulty: context.difficulty,
+                                practice: Some(card),
+                                hull: MAX_HULL,
+                                ammo: u32::MAX,
+                                perks: vec![],
+                            };
+                            next_state.set(Focus::Mission);
+                        }
+                    }
+
+                    ButtonPath::SelectDifficulty => {
+                        if let Some(select) = potential_difficulty {
+                            context.difficulty = select.0;
+                            next_state.set(Focus::Notice);
+                        }
+                    }
+
+                    ButtonPath::SavePreset => {
+                        let message = context.save_preset();
+                        for mut text in &mut feedback {
+                            **text = message.clone();
+                        }
+                    }
+
+                    ButtonPath::ApplyPreset => {
+                        if let Some(message) =
+                            potential_preset.and_then(|select| context.apply_preset(select.0))
+                        {
+                            for mut text in &mut feedback {
+                                **text = message.clone();
+                            }
+                        }
+                    }
+
+                    ButtonPath::DeletePreset => {
+                        if let Some(message) =
+                            potential_preset.and_then(|select| context.delete_preset(select.0))
+                        {
+                            for mut text in &mut feedback {
+                                **text = message.clone();
+                            }
+                        }
+                    }
+
+                    ButtonPath::SelectCraft => {
+                        context.selected_craft = (context.selected_craft + 1) % context.hangar.len();
+                    }
+
+                    ButtonPath::AssignPilot => {
+                        context.swap_pilot();
+                    }
                 }
             }
             Interaction::Hovered => {
@@ -461,6 +1089,11 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
         loadout: vec![],
         enemy: Enemies::RedGirl,
         map: Map::Day,
+        difficulty: Difficulty::Normal,
+        practice: None,
+        hull: MAX_HULL,
+        ammo: u32::MAX,
+        perks: vec![],
     });
 
     commands.insert_resource(XcomState {
@@ -553,23 +1186,101 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 cost: 150,
                 prerequisites: vec![Tech::MagicBullet],
                 progress: 0,
+            },
+            Research {
+                id: Tech::ChargeRocket,
+                equipable: true,
+                name: "Charge rocket".to_string(),
+                description: "Hold fire to pack more pixie dust into the warhead, a full charge is a rocket three times the size".to_string(),
+                cost: 60,
+                prerequisites: vec![Tech::Rocket],
+                progress: 0,
+            },
+            Research {
+                id: Tech::Laser,
+                equipable: true,
+                name: "Mana laser".to_string(),
+                description: "Focuses pixie dust into a beam that cuts straight through whatever it hits".to_string(),
+                cost: 80,
+                prerequisites: vec![],
+                progress: 0,
+            },
+            Research {
+                id: Tech::DeterganceT1,
+                equipable: true,
+                name: "Detergance plating".to_string(),
+                description: "A soapy film of anti-magic around the hull. Shrugs off a bullet and slowly foams back up".to_string(),
+                cost: 60,
+                prerequisites: vec![],
+                progress: 0,
+            },
+            Research {
+                id: Tech::DeterganceT2,
+                equipable: true,
+                name: "Industrial detergance".to_string(),
+                description: "Twice the suds, and they foam back faster. Takes two bullets before the hull is touched".to_string(),
+                cost: 120,
+                prerequisites: vec![Tech::DeterganceT1],
+                progress: 0,
+            },
+            Research {
+                id: Tech::Familiars,
+                equipable: true,
+                name: "Paper familiars".to_string(),
+                description: "A pair of folded drones that circle the ship and shoot alongside it. They line up in front of the nose while focusing".to_string(),
+                cost: 100,
+                prerequisites: vec![Tech::MagicBullet],
+                progress: 0,
             }
         ],
         active_missions: vec![],
         finished_missions: vec![],
         selected_research: None,
         selected_production: None,
-        loadout: HashMap::from([
-            (Slot::Front, Some(Tech::AmmoStockpile)),
-            (Slot::Engine, None),
-            (Slot::Core1, None),
-            (Slot::LeftWing1, Some(Tech::MachineGun)),
-            (Slot::RightWing1, Some(Tech::MachineGun)),
-        ]),
+        hangar: vec![
+            Craft::new(
+                "Skylark".to_string(),
+                HashMap::from([
+                    (Slot::Front, Some(Tech::AmmoStockpile)),
+                    (Slot::Engine, None),
+                    (Slot::Core1, None),
+                    (Slot::LeftWing1, Some(Tech::MachineGun)),
+                    (Slot::RightWing1, Some(Tech::MachineGun)),
+                    (Slot::Shield, None),
+                ]),
+                0,
+            )
+            .with_pilot(Pilot::new("Rin Hoshino".to_string())),
+            Craft::new(
+                "Kestrel".to_string(),
+                HashMap::from([
+                    (Slot::Front, None),
+                    (Slot::Engine, None),
+                    (Slot::Core1, None),
+                    (Slot::LeftWing1, Some(Tech::MachineGun)),
+                    (Slot::RightWing1, Some(Tech::MachineGun)),
+                    (Slot::Shield, None),
+                ]),
+                0,
+            )
+            .with_pilot(Pilot::new("Kei Tachibana".to_string())),
+        ],
+        selected_craft: 0,
+        crafts_built: 2,
+        presets: vec![],
+        pilots: vec![
+            Pilot::new("Mio Arakawa".to_string()),
+            Pilot::new("Sora Kudo".to_string()),
+        ],
+        pilots_hired: 4,
         timer: Timer::new(Duration::from_secs_f32(0.8), TimerMode::Repeating),
         speed: 5,
         notice_title: "".to_string(),
         notice_text: "".to_string(),
+        total_score: 0,
+        difficulty: Difficulty::Normal,
+        practice_map: Map::Day,
+        selected_mission: None,
         inventory: vec![
             Resources {
                 name: Scientists,
@@ -581,6 +1292,16 @@ fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
                 description: "A talented craftsman of the near arcane".to_string(),
                 amount: 5,
             },
+            Resources {
+                name: MagicDust,
+                description: "Glittering residue left behind by magical invaders".to_string(),
+                amount: 0,
+            },
+            Resources {
+                name: Ammunition,
+                description: "Rounds for the craft's guns, turned out by the engineers".to_string(),
+                amount: 3000,
+            },
         ]
         .into_iter()
         .map(|r| (r.name, r))
@@ -612,7 +1333,7 @@ fn spawn_mission(
                 requirment: vec![],
                 consequences: vec![],
                 rewards: vec![],
-                time_left: 10,
+                time_left: context.difficulty.mission_time(),
                 overworld_x: x,
                 overworld_y: y,
                 phase,
@@ -625,7 +1346,7 @@ fn spawn_mission(
                 requirment: vec![],
                 consequences: vec![],
                 rewards: vec![],
-                time_left: 10,
+                time_left: context.difficulty.mission_time(),
                 overworld_x: x,
                 overworld_y: y,
                 phase,
@@ -638,7 +1359,7 @@ fn spawn_mission(
                 requirment: vec![],
                 consequences: vec![],
                 rewards: vec![],
-                time_left: 10,
+                time_left: context.difficulty.mission_time(),
                 overworld_x: x,
                 overworld_y: y,
                 phase,
@@ -651,7 +1372,7 @@ fn spawn_mission(
                 requirment: vec![],
                 consequences: vec![],
                 rewards: vec![],
-                time_left: 10,
+                time_left: context.difficulty.mission_time(),
                 overworld_x: x,
                 overworld_y: y,
                 phase,
@@ -712,25 +1433,27 @@ fn move_enemies(
                     inventory,
                     notice_title,
                     notice_text,
+                    difficulty,
                     ..
                 } = &mut *context;
+                let penalty = difficulty.scientist_penalty();
                 let scientist: &mut usize = &mut inventory.get_mut(&Scientists).unwrap().amount;
 
                 *notice_title = "Invader sucess".to_string();
                 match mission.enemy {
                     Enemies::RedGirl => {
-                        if (*scientist > 2) {
-                            *scientist -= 2;
-                            *notice_text = "The magical girl keeps rampaging across town. Many lives are lost in her pyromaniac craze. You have lost 2 scientist in the carnage".to_string();
+                        if (*scientist > penalty) {
+                            *scientist -= penalty;
+                            *notice_text = format!("The magical girl keeps rampaging across town. Many lives are lost in her pyromaniac craze. You have lost {penalty} scientist in the carnage");
                             next_state.set(Focus::Notice);
                         } else {
                             game_over();
                         }
                     }
                     Enemies::Lizard => {
-                        if (*scientist > 2) {
-                            *scientist -= 2;
-                            *notice_text = "The lizardman manages to convert two of our finest scientist to their cause. You have lost 2 scientist in the carnage".to_string();
+                        if (*scientist > penalty) {
+                            *scientist -= penalty;
+                            *notice_text = format!("The lizardman manages to convert {penalty} of our finest scientist to their cause. You have lost {penalty} scientist in the carnage");
                             next_state.set(Focus::Notice);
                         } else {
                             game_over();
@@ -753,6 +1476,8 @@ fn move_enemies(
 fn on_xcom(
     mut commands: Commands,
     mut context: ResMut<XcomState>,
+    replays: Res<Replays>,
+    practice: Res<PracticeStats>,
     window: Single<&mut Window, With<bevy::window::PrimaryWindow>>,
     mut focus_state: ResMut<NextState<Focus>>,
     mut first_time: Local<bool>,
@@ -802,11 +1527,24 @@ fn on_xcom(
     //SpawnNoticeHud
     spawn_notice_hud(&mut commands, &context);
 
+    //SpawnReplayHud
+    spawn_replay_hud(&mut commands, &context, &replays);
+
+    //SpawnControlsHud
+    spawn_controls_hud(&mut commands, &context);
+
+    //SpawnDifficultyHud
+    spawn_difficulty_hud(&mut commands, &context);
+
+    //SpawnPracticeHud
+    spawn_practice_hud(&mut commands, &context, &practice);
+
     if (!(*first_time)) {
         *first_time = true;
         context.notice_title = "Hello Commander".to_string();
         context.notice_text = "Magical girls have started invading the nordic countries. It is up to your research their magic and launch a well equiped and elite task force. If we have atleast ten scientists we can launch a moon mission \nGood luck ".to_string();
-        focus_state.set(Focus::Notice);
+        // the notice comes up once a difficulty is picked
+        focus_state.set(Focus::Difficulty);
     }
 }
 
@@ -876,8 +1614,11 @@ fn load_xcom_assets(asset_server: &Res<AssetServer>) -> XcomResources {
             (Tech::EngineT1, asset_server.load("Xcom_hud/Fuel.png")),
             (Tech::EngineT2, asset_server.load("Xcom_hud/Fuel1.png")),
             (Tech::Rocket, asset_server.load("Xcom_hud/rocket.png")),
+            (Tech::ChargeRocket, asset_server.load("Xcom_hud/rocket.png")),
             (Tech::Phase, asset_server.load("Xcom_hud/Ghost.png")),
             (Tech::MachineGunT2, asset_server.load("Xcom_hud/gun2.png")),
+            (Tech::Laser, asset_server.load("bullets/Beam.png")),
+            (Tech::Familiars, asset_server.load("Xcom_hud/Flight.png")),
         ]),
         circle: asset_server.load("Enemies/Redcirle.png"),
         geo_music: asset_server.load("Music/Calm1.ogg"),
@@ -996,6 +1737,9 @@ fn create_mission_notice(
 #[derive(Event)]
 struct ScienceChanged;
 
+/// Rounds every engineer turns out per tick of the clock.
+const AMMO_PER_ENGINEER: usize = 10;
+
 fn make_techs(
     mut ticks: EventReader<XcomTick>,
     mut commands: Commands,
@@ -1008,6 +1752,8 @@ fn make_techs(
         let scientists: usize = context.inventory[&Scientists].amount;
         let engineers: usize = context.inventory[&Engineer].amount;
 
+        context.inventory.get_mut(&Ammunition).unwrap().amount += engineers * AMMO_PER_ENGINEER;
+
         let mut finished = false;
         {
             let XcomState {
@@ -0,0 +1,149 @@
+use super::*;
+
+/// Lives a craft in perfect condition goes into a fight with.
+pub const MAX_HULL: usize = 3;
+const DAY: usize = 24 * 60;
+/// game days of work to patch up one point of hull
+const REPAIR_DAYS_PER_HULL: usize = 1;
+/// game days until a destroyed craft's replacement is delivered
+const REPLACEMENT_DAYS: usize = 3;
+const CRAFT_NAMES: [&str; 5] = ["Skylark", "Kestrel", "Heron", "Swift", "Magpie"];
+
+pub fn hangar_plugin(app: &mut App) {
+    app.add_systems(Update, repair_craft.run_if(in_state(GameState::Xcom)))
+        .add_systems(
+            Update,
+            update_craft_label.run_if(in_state(GameState::Xcom).and(in_state(Focus::Mission))),
+        );
+}
+
+#[derive(Clone, Debug)]
+pub struct Craft {
+    pub name: String,
+    pub loadout: HashMap<Slot, Option<Tech>>,
+    pub pilot: Option<Pilot>,
+    pub hull: usize,
+    /// minutes of work done towards the next point of hull
+    repair_progress: usize,
+    /// the game time the craft is delivered at, it can't fly before that
+    ready_at: usize,
+}
+
+impl Craft {
+    pub fn new(name: String, loadout: HashMap<Slot, Option<Tech>>, ready_at: usize) -> Self {
+        Self {
+            name,
+            loadout,
+            pilot: None,
+            hull: MAX_HULL,
+            repair_progress: 0,
+            ready_at,
+        }
+    }
+
+    pub fn with_pilot(mut self, pilot: Pilot) -> Self {
+        self.pilot = Some(pilot);
+        self
+    }
+
+    pub fn ready(&self, time: usize) -> bool {
+        time >= self.ready_at
+    }
+
+    /// Wears the hull down by the lives lost in a fight the craft came back
+    /// from. Whatever happened, it limps home with at least one point left.
+    pub fn take_damage(&mut self, lives_lost: u32) {
+        self.hull = self.hull.saturating_sub(lives_lost as usize).max(1);
+    }
+
+    pub fn status(&self, time: usize) -> String {
+        if !self.ready(time) {
+            let days = (self.ready_at - time).div_ceil(DAY);
+            return format!["{}: delivered in {days} day(s)", self.name];
+        }
+
+        if self.hull < MAX_HULL {
+            let left = (MAX_HULL - self.hull) * REPAIR_DAYS_PER_HULL * DAY - self.repair_progress;
+            return format![
+                "{}: hull {}/{MAX_HULL}, repaired in {} day(s)",
+                self.name,
+                self.hull,
+                left.div_ceil(DAY)
+            ];
+        }
+
+        format!["{}: hull {MAX_HULL}/{MAX_HULL}", self.name]
+    }
+}
+
+pub fn empty_loadout() -> HashMap<Slot, Option<Tech>> {
+    Slot::ALL.into_iter().map(|slot| (slot, None)).collect()
+}
+
+impl XcomState {
+    /// The craft the mission screen is showing and the next fight is flown in.
+    pub fn craft(&self) -> &Craft {
+        &self.hangar[self.selected_craft]
+    }
+
+    pub fn craft_mut(&mut self) -> &mut Craft {
+        &mut self.hangar[self.selected_craft]
+    }
+
+    /// Writes off the selected craft and orders a bare replacement for it.
+    pub fn lose_craft(&mut self) -> Craft {
+        let lost = self.hangar.remove(self.selected_craft);
+
+        let name = CRAFT_NAMES[self.crafts_built % CRAFT_NAMES.len()];
+        let name = match self.crafts_built / CRAFT_NAMES.len() {
+            0 => name.to_string(),
+            n => format!["{name} {}", n + 1],
+        };
+        self.crafts_built += 1;
+
+        self.hangar.push(Craft::new(
+            name,
+            empty_loadout(),
+            self.time + REPLACEMENT_DAYS * DAY,
+        ));
+        self.selected_craft = 0;
+
+        lost
+    }
+}
+
+fn repair_craft(mut ticks: EventReader<XcomTick>, mut context: ResMut<XcomState>) {
+    for _ in ticks.read() {
+        for craft in &mut context.hangar {
+            if craft.hull >= MAX_HULL {
+                continue;
+            }
+
+            // the clock moves in half hour steps
+            craft.repair_progress += 30;
+
+            if craft.repair_progress >= REPAIR_DAYS_PER_HULL * DAY {
+                craft.repair_progress = 0;
+                craft.hull += 1;
+            }
+        }
+    }
+}
+
+/// Shows which craft is selected and what shape it's in.
+#[derive(Component)]
+pub struct CraftLabel;
+
+fn update_craft_label(context: Res<XcomState>, mut labels: Query<&mut Text, With<CraftLabel>>) {
+    let status = format![
+        "{}\n{} rounds in stock",
+        context.craft().status(context.time),
+        context.inventory[&ResourceType::Ammunition].amount
+    ];
+
+    for mut text in &mut labels {
+        if **text != status {
+            **text = status.clone();
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use rand::seq::IndexedRandom;
+
+use super::*;
+
+/// Experience the first perk costs, every level after that costs this much more.
+const XP_PER_LEVEL: u32 = 100;
+const PILOT_NAMES: [&str; 6] = [
+    "Rin Hoshino",
+    "Kei Tachibana",
+    "Mio Arakawa",
+    "Sora Kudo",
+    "Nao Fujibayashi",
+    "Yuki Sagara",
+];
+
+pub fn pilots_plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        update_pilot_label.run_if(in_state(GameState::Xcom).and(in_state(Focus::Mission))),
+    );
+}
+
+#[derive(Clone, Debug)]
+pub struct Pilot {
+    pub name: String,
+    /// towards the next perk, spent when one is learned
+    pub xp: u32,
+    pub perks: Vec<Perk>,
+}
+
+impl Pilot {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            xp: 0,
+            perks: vec![],
+        }
+    }
+
+    fn next_level(&self) -> u32 {
+        XP_PER_LEVEL * (self.perks.len() as u32 + 1)
+    }
+
+    /// Hands out experience for a fight the pilot came back from, returning
+    /// the perks it was enough for.
+    pub fn gain_xp(&mut self, result: &MissionResult) -> (u32, Vec<Perk>) {
+        let xp = result.damage_dealt / 50 + result.grazes / 5 + result.survived_secs as u32 / 2;
+        self.xp += xp;
+
+        let mut learned = vec![];
+        let mut rng = rand::rng();
+
+        while self.xp >= self.next_level() {
+            let unknown: Vec<_> = Perk::ALL
+                .into_iter()
+                .filter(|perk| !self.perks.contains(perk))
+                .collect();
+
+            // nothing left to learn, the experience just piles up
+            let Some(&perk) = unknown.choose(&mut rng) else {
+                break;
+            };
+
+            self.xp -= self.next_level();
+            self.perks.push(perk);
+            learned.push(perk);
+        }
+
+        (xp, learned)
+    }
+
+    pub fn status(&self) -> String {
+        let perks: Vec<_> = self.perks.iter().map(|perk| perk.label()).collect();
+
+        format![
+            "{}, level {} ({}/{} xp){}{}",
+            self.name,
+            self.perks.len() + 1,
+            self.xp,
+            self.next_level(),
+            if perks.is_empty() { "" } else { ": " },
+            perks.join(", ")
+        ]
+    }
+}
+
+impl XcomState {
+    /// Takes on a rookie, to fill the place of someone who didn't come back.
+    pub fn hire_pilot(&mut self) -> String {
+        let name = PILOT_NAMES[self.pilots_hired % PILOT_NAMES.len()];
+        let name = match self.pilots_hired / PILOT_NAMES.len() {
+            0 => name.to_string(),
+            n => format!["{name} {}", n + 1],
+        };
+        self.pilots_hired += 1;
+
+        self.pilots.push(Pilot::new(name.clone()));
+        name
+    }
+
+    /// Gives the pilot of the selected craft their experience for the fight
+    /// and says how it went for them.
+    pub fn debrief_pilot(&mut self, result: &MissionResult) -> String {
+        let Some(pilot) = &mut self.craft_mut().pilot else {
+            return String::new();
+        };
+
+        let (xp, learned) = pilot.gain_xp(result);
+        let mut text = format!["{} earned {xp} xp", pilot.name];
+        for perk in learned {
+            text += &format![" and learned {}", perk.label()];
+        }
+
+        text
+    }
+
+    /// Puts the next pilot from the reserve in the selected craft, and whoever
+    /// was flying it at the back of the reserve.
+    pub fn swap_pilot(&mut self) {
+        let next = (!self.pilots.is_empty()).then(|| self.pilots.remove(0));
+
+        if let Some(current) = self.craft_mut().pilot.take() {
+            self.pilots.push(current);
+        }
+
+        self.craft_mut().pilot = next;
+    }
+}
+
+/// Shows who's flying the selected craft.
+#[derive(Component)]
+pub struct PilotLabel;
+
+fn update_pilot_label(context: Res<XcomState>, mut labels: Query<&mut Text, With<PilotLabel>>) {
+    let status = match &context.craft().pilot {
+        Some(pilot) => pilot.status(),
+        None => "No pilot assigned".to_string(),
+    };
+
+    for mut text in &mut labels {
+        if **text != status {
+            **text = status.clone();
+        }
+    }
+}
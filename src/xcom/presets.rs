@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use super::*;
+
+const MAX_PRESETS: usize = 5;
+
+pub fn presets_plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        (refresh_preset_list, sync_loadout_icons)
+            .chain()
+            .run_if(in_state(GameState::Xcom).and(in_state(Focus::Mission))),
+    );
+}
+
+/// A saved ship loadout. They're kept in [`XcomState::presets`], so they only
+/// ever hold techs the campaign they were saved in has researched. The
+/// campaign itself isn't written to disk, so presets are gone once the game
+/// is closed.
+#[derive(Clone, Debug)]
+pub struct LoadoutPreset {
+    pub name: String,
+    pub slots: BTreeMap<Slot, Tech>,
+}
+
+impl XcomState {
+    /// The first "Preset n" that isn't taken yet.
+    fn next_preset_name(&self) -> String {
+        (1..)
+            .map(|n| format!["Preset {n}"])
+            .find(|name| self.presets.iter().all(|preset| &preset.name != name))
+            .unwrap()
+    }
+}
+
+/// Where the saved presets are listed on the mission screen.
+#[derive(Component)]
+pub struct PresetList;
+
+impl XcomState {
+    /// Saves the selected craft's loadout as a new preset, returning what to
+    /// tell the player.
+    pub fn save_preset(&mut self) -> String {
+        if self.presets.len() >= MAX_PRESETS {
+            return format!["Only {MAX_PRESETS} presets fit, delete one first"];
+        }
+
+        let name = self.next_preset_name();
+        let slots = self
+            .craft()
+            .loadout
+            .iter()
+            .filter_map(|(&slot, &tech)| Some((slot, tech?)))
+            .collect();
+
+        self.presets.push(LoadoutPreset {
+            name: name.clone(),
+            slots,
+        });
+        format!["Saved the loadout as {name}"]
+    }
+
+    /// Swaps the whole loadout for the preset, leaving out anything that
+    /// doesn't fit its slot anymore.
+    pub fn apply_preset(&mut self, index: usize) -> Option<String> {
+        let preset = self.presets.get(index)?.clone();
+        let mut skipped = 0;
+
+        for slot in Slot::ALL {
+            let tech = preset.slots.get(&slot).copied().filter(|&tech| {
+                let known = self
+                    .finished_research
+                    .iter()
+                    .any(|research| research.id == tech && research.equipable);
+
+                skipped += (!known || !slot.fits(tech)) as usize;
+                known && slot.fits(tech)
+            });
+
+            self.craft_mut().loadout.insert(slot, tech);
+        }
+
+        Some(match skipped {
+            0 => format!["Equipped {}", preset.name],
+            _ => format!["Equipped {}, {skipped} item(s) left out", preset.name],
+        })
+    }
+
+    pub fn delete_preset(&mut self, index: usize) -> Option<String> {
+        if index >= self.presets.len() {
+            return None;
+        }
+
+        let preset = self.presets.remove(index);
+        Some(format!["Deleted {}", preset.name])
+    }
+}
+
+// the campaign state changes every tick, so go by the names on the list
+fn refresh_preset_list(
+    mut commands: Commands,
+    context: Res<XcomState>,
+    lists: Query<(Entity, Ref<PresetList>)>,
+    mut shown: Local<Vec<String>>,
+) {
+    let names: Vec<_> = context.presets.iter().map(|p| p.name.clone()).collect();
+
+    for (list, marker) in &lists {
+        if names == *shown && !marker.is_added() {
+            continue;
+        }
+
+        commands
+            .entity(list)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (i, preset) in context.presets.iter().enumerate() {
+                    make_small_button(
+                        parent,
+                        &preset.name,
+                        ButtonPath::ApplyPreset,
+                        PresetSelect(i),
+                        &context,
+                    );
+                    make_small_button(
+                        parent,
+                        "x",
+                        ButtonPath::DeletePreset,
+                        PresetSelect(i),
+                        &context,
+                    );
+                }
+            });
+    }
+
+    *shown = names;
+}
+
+/// Keeps the slot icons in line with the loadout, for changes that don't go
+/// through the equip systems, like applying a preset.
+fn sync_loadout_icons(
+    context: Res<XcomState>,
+    slots: Query<(&ShipComponent, &Children)>,
+    mut images: Query<&mut ImageNode, With<LoadoutIcon>>,
+) {
+    for (component, children) in &slots {
+        let wanted = match context.craft().loadout[&component.0] {
+            Some(tech) => &context.assets.icons[&tech],
+            None => match component.0 {
+                Slot::Core1 | Slot::Engine => &context.assets.button_equip_alt,
+                _ => &context.assets.button_equip,
+            },
+        };
+
+        if let Ok(mut node) = images.get_mut(children[0])
+            && &node.image != wanted
+        {
+            node.image = wanted.clone();
+        }
+    }
+}
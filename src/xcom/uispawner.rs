@@ -1,6 +1,8 @@
 use bevy::input::mouse::MouseScrollUnit;
 use bevy::input::mouse::MouseWheel;
+use bevy::picking::events::{Click, Drag, DragDrop, DragEnd, DragStart, Pointer};
 use bevy::picking::focus::HoverMap;
+use bevy::picking::pointer::PointerButton;
 
 use crate::prelude::*;
 use crate::xcom::*;
@@ -49,6 +51,9 @@ pub fn spawn_geo_hud(commands: &mut Commands, context: &XcomState) {
                 |name, id| make_button(parent, name, id, context, Val::Px(256.0), Val::Px(64.0));
 
             make_geo_button("Research", ButtonPath::ScienceMenu);
+            make_geo_button("Practice", ButtonPath::PracticeMenu);
+            make_geo_button("Replays", ButtonPath::ReplayMenu);
+            make_geo_button("Controls", ButtonPath::ControlsMenu);
         });
 }
 
@@ -371,6 +376,377 @@ pub fn spawn_notice_hud(commands: &mut Commands, context: &XcomState) {
     );
 }
 
+pub fn spawn_replay_hud(commands: &mut Commands, context: &XcomState, replays: &Replays) {
+    commands.spawn_hud(
+        context,
+        ReplayScreen,
+        |parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(15.0),
+                    top: Val::Vh(5.0),
+                    ..default_button_node()
+                },
+                Text::new(if replays.recorded.is_empty() {
+                    "No fights recorded yet"
+                } else {
+                    "Replays"
+                }),
+                TextFont {
+                    font: context.assets.font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(65.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|list| {
+                    // newest first
+                    for (i, replay) in replays.recorded.iter().enumerate().rev() {
+                        make_replay_button(list, i, replay, context);
+                    }
+                });
+
+            make_button(
+                parent,
+                "Exit",
+                ButtonPath::MainMenu,
+                context,
+                Val::Percent(100.),
+                Val::Px(128.),
+            );
+        },
+        true,
+    );
+}
+
+fn make_replay_button(
+    parent: &mut ChildBuilder,
+    index: usize,
+    replay: &Replay,
+    context: &XcomState,
+) {
+    parent
+        .spawn((
+            Button,
+            ButtonLink(ButtonPath::WatchReplay),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(80.0),
+                flex_shrink: 0.0,
+                ..default_button_node()
+            },
+            ReplaySelect(index),
+            ImageNode::new(context.assets.button_normal.clone()),
+        ))
+        .insert(PickingBehavior {
+            should_block_lower: false,
+            ..default()
+        })
+        .with_child((
+            Text::new(format!(
+                "#{} {:?} ({:?}) - score {}",
+                index + 1,
+                replay.params.enemy,
+                replay.params.difficulty,
+                replay.score
+            )),
+            TextFont {
+                font: context.assets.font.clone(),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.9)),
+            PickingBehavior {
+                should_block_lower: false,
+                ..default()
+            },
+        ));
+}
+
+pub fn spawn_practice_hud(commands: &mut Commands, context: &XcomState, practice: &PracticeStats) {
+    commands.spawn_hud(
+        context,
+        PracticeScreen,
+        |parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(15.0),
+                    top: Val::Vh(5.0),
+                    ..default_button_node()
+                },
+                Text::new("Spellcard practice"),
+                TextFont {
+                    font: context.assets.font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            parent
+                .spawn((
+                    Button,
+                    ButtonLink(ButtonPath::PracticeMap),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(64.0),
+                        flex_shrink: 0.0,
+                        ..default_button_node()
+                    },
+                    ImageNode::new(context.assets.button_normal.clone()),
+                ))
+                .insert(PickingBehavior {
+                    should_block_lower: false,
+                    ..default()
+                })
+                .with_child((
+                    // filled in by update_practice_map_label
+                    Text::new("Map"),
+                    PracticeMapLabel,
+                    TextFont {
+                        font: context.assets.font.clone(),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.7, 0.7, 0.9)),
+                    PickingBehavior {
+                        should_block_lower: false,
+                        ..default()
+                    },
+                ));
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(55.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|list| {
+                    for enemy in Enemies::ALL {
+                        for (i, card) in touhou::spellcards(enemy).iter().enumerate() {
+                            make_practice_button(list, enemy, i, card, practice, context);
+                        }
+                    }
+                });
+
+            make_button(
+                parent,
+                "Exit",
+                ButtonPath::MainMenu,
+                context,
+                Val::Percent(100.),
+                Val::Px(128.),
+            );
+        },
+        true,
+    );
+}
+
+fn make_practice_button(
+    parent: &mut ChildBuilder,
+    enemy: Enemies,
+    index: usize,
+    card: &touhou::SpellcardInfo,
+    practice: &PracticeStats,
+    context: &XcomState,
+) {
+    let stats = practice.card(enemy, index);
+
+    parent
+        .spawn((
+            Button,
+            ButtonLink(ButtonPath::PracticeCard),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(64.0),
+                flex_shrink: 0.0,
+                ..default_button_node()
+            },
+            PracticeSelect(enemy, index),
+            ImageNode::new(context.assets.button_normal.clone()),
+        ))
+        .insert(PickingBehavior {
+            should_block_lower: false,
+            ..default()
+        })
+        .with_child((
+            Text::new(format!(
+                "{:?}: {} - {}/{}",
+                enemy, card.name, stats.captures, stats.attempts
+            )),
+            TextFont {
+                font: context.assets.font.clone(),
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.9)),
+            PickingBehavior {
+                should_block_lower: false,
+                ..default()
+            },
+        ));
+}
+
+pub fn spawn_controls_hud(commands: &mut Commands, context: &XcomState) {
+    commands.spawn_hud(
+        context,
+        ControlsScreen,
+        |parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(15.0),
+                    top: Val::Vh(5.0),
+                    ..default_button_node()
+                },
+                Text::new("Controls"),
+                TextFont {
+                    font: context.assets.font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(65.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    ..default()
+                })
+                .with_children(|list| {
+                    for action in Action::ALL {
+                        make_rebind_button(list, action, context);
+                    }
+                });
+
+            make_button(
+                parent,
+                "Exit",
+                ButtonPath::MainMenu,
+                context,
+                Val::Percent(100.),
+                Val::Px(128.),
+            );
+        },
+        true,
+    );
+}
+
+fn make_rebind_button(parent: &mut ChildBuilder, action: Action, context: &XcomState) {
+    parent
+        .spawn((
+            Button,
+            ButtonLink(ButtonPath::Rebind),
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(64.0),
+                flex_shrink: 0.0,
+                ..default_button_node()
+            },
+            RebindSelect(action),
+            ImageNode::new(context.assets.button_normal.clone()),
+        ))
+        .insert(PickingBehavior {
+            should_block_lower: false,
+            ..default()
+        })
+        .with_child((
+            // filled in by update_binding_labels
+            Text::new(action.label()),
+            BindingLabel(action),
+            TextFont {
+                font: context.assets.font.clone(),
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.9)),
+            PickingBehavior {
+                should_block_lower: false,
+                ..default()
+            },
+        ));
+}
+
+pub fn spawn_difficulty_hud(commands: &mut Commands, context: &XcomState) {
+    commands.spawn_hud(
+        context,
+        DifficultyScreen,
+        |parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(80.0),
+                    left: Val::Percent(10.),
+                    height: Val::Percent(15.0),
+                    top: Val::Vh(5.0),
+                    ..default_button_node()
+                },
+                Text::new("Choose a difficulty"),
+                TextFont {
+                    font: context.assets.font.clone(),
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+            for difficulty in Difficulty::ALL {
+                parent
+                    .spawn((
+                        Button,
+                        ButtonLink(ButtonPath::SelectDifficulty),
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(96.0),
+                            ..default_button_node()
+                        },
+                        DifficultySelect(difficulty),
+                        ImageNode::new(context.assets.button_normal.clone()),
+                    ))
+                    .insert(PickingBehavior {
+                        should_block_lower: false,
+                        ..default()
+                    })
+                    .with_child((
+                        Text::new(format!("{difficulty:?}")),
+                        TextFont {
+                            font: context.assets.font.clone(),
+                            font_size: 33.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.7, 0.9)),
+                        PickingBehavior {
+                            should_block_lower: false,
+                            ..default()
+                        },
+                    ));
+            }
+        },
+        true,
+    );
+}
+
 trait UiExt {
     fn spawn_hud<T: Component, F>(&mut self, ctx: &XcomState, marker: T, builder: F, row: bool)
     where
@@ -417,6 +793,45 @@ impl UiExt for Commands<'_, '_> {
     }
 }
 
+pub fn make_small_button(
+    parent: &mut ChildBuilder,
+    text: &str,
+    link_id: ButtonPath,
+    select: impl Bundle,
+    context: &XcomState,
+) {
+    parent
+        .spawn((
+            Button,
+            ButtonLink(link_id),
+            select,
+            Node {
+                height: Val::Px(48.0),
+                padding: UiRect::horizontal(Val::Px(12.0)),
+                margin: UiRect::right(Val::Px(6.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ImageNode::new(context.assets.button_normal.clone()),
+        ))
+        .insert(PickingBehavior {
+            should_block_lower: false,
+            ..default()
+        })
+        .with_child((
+            Text::new(text.to_string()),
+            TextFont {
+                font: context.assets.font.clone(),
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.9)),
+            // clicks should land on the button itself
+            PickingBehavior::IGNORE,
+        ));
+}
+
 fn default_button_node() -> Node {
     Node {
         // horizontally center child text
@@ -438,7 +853,7 @@ pub fn unequip_loadout(
     for (interaction, component, mut children) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
             dbg!(*interaction);
-            context.loadout.insert(component.0, None);
+            context.craft_mut().loadout.insert(component.0, None);
             let mut node = image_query.get_mut(children[0]).unwrap();
 
             *node = match **component {
@@ -451,46 +866,195 @@ pub fn unequip_loadout(
     }
 }
 
+/// Clicking a piece of equipment puts it in the first empty slot it fits.
 pub fn equip_loadout(
     mut context: ResMut<XcomState>,
-    mut interaction_query: Query<(&Interaction, &Equipment), (Changed<Interaction>)>,
-    mut loadout_query: Query<(&ShipComponent, &mut Children)>,
+    mut clicks: EventReader<Pointer<Click>>,
+    equipment: Query<&Equipment>,
+    loadout_query: Query<(&ShipComponent, &Children)>,
     mut image_query: Query<&mut ImageNode>,
-    mut pressed_already: Local<bool>,
+    mut feedback: Query<&mut Text, With<LoadoutFeedback>>,
 ) {
-    for (interaction, equipment) in &mut interaction_query {
-        println!("About to equip");
-        if *interaction == Interaction::Pressed {
-            if !*pressed_already {
-                *pressed_already = true;
-                dbg!(*interaction);
-                let XcomState {
-                    assets, loadout, ..
-                } = &mut *context;
-                for (key, value) in loadout {
-                    if value.is_none() {
-                        println!("Equip!");
-                        *value = Some(equipment.0);
-
-                        //We must find the box to fill from key
-                        for (component, mut children) in &mut loadout_query {
-                            if component.0 == *key {
-                                let mut node = image_query.get_mut(children[0]).unwrap();
-                                *node = ImageNode::new(assets.icons[&equipment.0].clone());
-                                return;
-                            }
-                        }
-                    }
-                }
-            } else {
-                continue;
+    for click in clicks.read() {
+        if click.button != PointerButton::Primary {
+            continue;
+        }
+        let Ok(&Equipment(tech)) = equipment.get(click.target) else {
+            continue;
+        };
+
+        let free = Slot::ALL
+            .into_iter()
+            .find(|slot| slot.fits(tech) && context.craft().loadout[slot].is_none());
+
+        let message = match free {
+            Some(slot) => {
+                fill_slot(&mut context, slot, tech, &loadout_query, &mut image_query);
+                format!["{} fitted to the {} slot", tech_name(&context, tech), slot.label()]
             }
+            None => format![
+                "No free slot takes {} equipment, drag it onto one to swap",
+                tech.category().label()
+            ],
+        };
+
+        for mut text in &mut feedback {
+            **text = message.clone();
+        }
+    }
+}
+
+pub fn drag_equipment(
+    mut commands: Commands,
+    context: Res<XcomState>,
+    mut starts: EventReader<Pointer<DragStart>>,
+    mut drags: EventReader<Pointer<Drag>>,
+    mut ends: EventReader<Pointer<DragEnd>>,
+    equipment: Query<&Equipment>,
+    mut ghosts: Query<(Entity, &mut Node), With<DragGhost>>,
+) {
+    const GHOST_SIZE: f32 = 64.0;
+
+    for start in starts.read() {
+        let Ok(&Equipment(tech)) = equipment.get(start.target) else {
+            continue;
+        };
+        let pos = start.pointer_location.position - GHOST_SIZE / 2.0;
+
+        commands.spawn((
+            XcomObject,
+            DragGhost,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Px(GHOST_SIZE),
+                height: Val::Px(GHOST_SIZE),
+                left: Val::Px(pos.x),
+                top: Val::Px(pos.y),
+                ..default()
+            },
+            ImageNode::new(context.assets.icons[&tech].clone()),
+            // so the slot underneath still gets the drop
+            PickingBehavior::IGNORE,
+            GlobalZIndex(10),
+        ));
+    }
+
+    for drag in drags.read() {
+        let pos = drag.pointer_location.position - GHOST_SIZE / 2.0;
+
+        for (_, mut node) in &mut ghosts {
+            node.left = Val::Px(pos.x);
+            node.top = Val::Px(pos.y);
+        }
+    }
+
+    if ends.read().count() > 0 {
+        for (ghost, _) in &ghosts {
+            commands.entity(ghost).despawn_recursive();
+        }
+    }
+}
+
+/// Dropping equipment on a slot puts it there, replacing whatever was in it,
+/// as long as the slot takes that kind of equipment.
+pub fn drop_equipment(
+    mut context: ResMut<XcomState>,
+    mut drops: EventReader<Pointer<DragDrop>>,
+    equipment: Query<&Equipment>,
+    loadout_query: Query<(&ShipComponent, &Children)>,
+    mut image_query: Query<&mut ImageNode>,
+    mut feedback: Query<&mut Text, With<LoadoutFeedback>>,
+) {
+    for drop in drops.read() {
+        let (Ok(&Equipment(tech)), Ok((component, _))) =
+            (equipment.get(drop.dropped), loadout_query.get(drop.target))
+        else {
+            continue;
+        };
+        let slot = component.0;
+
+        let message = if slot.fits(tech) {
+            fill_slot(&mut context, slot, tech, &loadout_query, &mut image_query);
+            format!["{} fitted to the {} slot", tech_name(&context, tech), slot.label()]
         } else {
-            *pressed_already = false;
+            let accepted: Vec<_> = slot.accepts().iter().map(|c| c.label()).collect();
+
+            format![
+                "{} is {} equipment, the {} slot only takes {}",
+                tech_name(&context, tech),
+                tech.category().label(),
+                slot.label(),
+                accepted.join(" or ")
+            ]
+        };
+
+        for mut text in &mut feedback {
+            **text = message.clone();
+        }
+    }
+}
+
+pub fn update_stat_summary(
+    context: Res<XcomState>,
+    mission_params: Res<MissionParams>,
+    mut summaries: Query<&mut Text, With<StatSummary>>,
+) {
+    let craft = context.craft();
+    let perks = craft.pilot.as_ref().map_or(&[][..], |pilot| &pilot.perks);
+
+    // the simulator flies a craft in perfect shape with all the ammo it wants
+    let (hull, stock) = if mission_params.practice.is_some() {
+        (mission_params.hull, mission_params.ammo as usize)
+    } else {
+        (craft.hull, context.inventory[&ResourceType::Ammunition].amount)
+    };
+    let stats = ShipStats::for_ship(hull, craft.loadout.values().flatten().copied(), perks);
+
+    let summary = format![
+        "Speed {:.0}  Damage x{:.2}\nLives {:.0}  Bombs {:.0}  Hitbox {:.1}  Shield {:.0}  Ammo {}/{:.0}",
+        stats.speed,
+        stats.damage,
+        stats.lives,
+        stats.bombs,
+        stats.hitbox,
+        stats.shield,
+        (stats.ammo as usize).min(stock),
+        stats.ammo,
+    ];
+
+    for mut text in &mut summaries {
+        if **text != summary {
+            **text = summary.clone();
         }
     }
 }
 
+fn fill_slot(
+    context: &mut XcomState,
+    slot: Slot,
+    tech: Tech,
+    loadout_query: &Query<(&ShipComponent, &Children)>,
+    image_query: &mut Query<&mut ImageNode>,
+) {
+    context.craft_mut().loadout.insert(slot, Some(tech));
+
+    for (component, children) in loadout_query {
+        if component.0 == slot
+            && let Ok(mut node) = image_query.get_mut(children[0])
+        {
+            *node = ImageNode::new(context.assets.icons[&tech].clone());
+        }
+    }
+}
+
+fn tech_name(context: &XcomState, tech: Tech) -> String {
+    context
+        .finished_research
+        .iter()
+        .find(|research| research.id == tech)
+        .map_or_else(|| format!("{tech:?}"), |research| research.name.clone())
+}
+
 pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
     commands.spawn_hud(
         context,
@@ -513,7 +1077,7 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                 ))
                 .with_children(|ship_box| {
                     //An if only for the assets. I wanna die..
-                    if let Some(tech) = context.loadout[&Slot::Front] {
+                    if let Some(tech) = context.craft().loadout[&Slot::Front] {
                         make_ship_icon(
                             ship_box,
                             context.assets.icons[&tech].clone(),
@@ -533,7 +1097,7 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                         );
                     }
 
-                    if let Some(tech) = context.loadout[&Slot::Core1] {
+                    if let Some(tech) = context.craft().loadout[&Slot::Core1] {
                         make_ship_icon(
                             ship_box,
                             context.assets.icons[&tech].clone(),
@@ -553,7 +1117,7 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                         );
                     }
 
-                    if let Some(tech) = context.loadout[&Slot::Engine] {
+                    if let Some(tech) = context.craft().loadout[&Slot::Engine] {
                         make_ship_icon(
                             ship_box,
                             context.assets.icons[&tech].clone(),
@@ -573,7 +1137,27 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                         );
                     }
 
-                    if let Some(tech) = context.loadout[&Slot::LeftWing1] {
+                    if let Some(tech) = context.craft().loadout[&Slot::Shield] {
+                        make_ship_icon(
+                            ship_box,
+                            context.assets.icons[&tech].clone(),
+                            context,
+                            Val::Px(0.0),
+                            Val::Px(96.0),
+                            Slot::Shield,
+                        );
+                    } else {
+                        make_ship_icon(
+                            ship_box,
+                            context.assets.button_equip.clone(),
+                            context,
+                            Val::Px(0.0),
+                            Val::Px(96.0),
+                            Slot::Shield,
+                        );
+                    }
+
+                    if let Some(tech) = context.craft().loadout[&Slot::LeftWing1] {
                         make_ship_icon(
                             ship_box,
                             context.assets.icons[&tech].clone(),
@@ -593,7 +1177,7 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                         );
                     }
 
-                    if let Some(tech) = context.loadout[&Slot::RightWing1] {
+                    if let Some(tech) = context.craft().loadout[&Slot::RightWing1] {
                         make_ship_icon(
                             ship_box,
                             context.assets.icons[&tech].clone(),
@@ -672,6 +1256,92 @@ pub fn spawn_mission_hud(commands: &mut Commands, context: &XcomState) {
                             },
                             TextColor(Color::srgb(0.9, 0.7, 0.9)),
                         ));
+                    parent.spawn((
+                        Text::new("Click equipment or drag it onto a slot"),
+                        LoadoutFeedback,
+                        TextFont {
+                            font: context.assets.font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.6)),
+                    ));
+                    parent
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::top(Val::Px(10.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            make_small_button(
+                                row,
+                                "Next craft",
+                                ButtonPath::SelectCraft,
+                                (),
+                                context,
+                            );
+                            make_small_button(
+                                row,
+                                "Next pilot",
+                                ButtonPath::AssignPilot,
+                                (),
+                                context,
+                            );
+                            row.spawn((
+                                Text::new(""),
+                                CraftLabel,
+                                TextFont {
+                                    font: context.assets.font.clone(),
+                                    font_size: 24.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                            ));
+                        });
+                    parent.spawn((
+                        Text::new(""),
+                        StatSummary,
+                        TextFont {
+                            font: context.assets.font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.7, 0.9, 0.7)),
+                    ));
+                    parent.spawn((
+                        Text::new(""),
+                        PilotLabel,
+                        TextFont {
+                            font: context.assets.font.clone(),
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                    ));
+                    parent
+                        .spawn(Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            margin: UiRect::top(Val::Px(10.0)),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            make_small_button(
+                                row,
+                                "Save preset",
+                                ButtonPath::SavePreset,
+                                (),
+                                context,
+                            );
+                            row.spawn((
+                                PresetList,
+                                Node {
+                                    flex_direction: FlexDirection::Row,
+                                    ..default()
+                                },
+                            ));
+                        });
                     parent
                         .spawn(Node {
                             min_width: Val::Percent(100.0),
@@ -726,6 +1396,8 @@ fn make_equipment(parent: &mut ChildBuilder, context: &XcomState, equipment_type
                 ..default()
             },
             ImageNode::new(context.assets.icons[&equipment_type.0].clone()),
+            // clicks and drags should land on the equipment itself
+            PickingBehavior::IGNORE,
         ));
 }
 
@@ -784,5 +1456,7 @@ fn make_ship_icon(
                 ..default()
             },
             ImageNode::new(image_handler),
+            // drops should land on the slot itself
+            PickingBehavior::IGNORE,
         ));
 }